@@ -1,8 +1,7 @@
 // Add crate-level attributes
 #![doc(html_root_url = "https://docs.rs/classnames-const/0.1.0")]
 #![deny(missing_docs)]
-// Remove unsafe forbid since we use unsafe transmute
-// #![forbid(unsafe_code)]
+#![forbid(unsafe_code)]
 
 //! A Rust macro library for compile-time CSS class name concatenation and processing
 //!
@@ -31,6 +30,14 @@
 //! assert_eq!(COMPLEX_CLASS, "header main footer");
 //! ```
 //!
+//! Prefixing every class for isolation:
+//! ```rust
+//! use classnames_const_rs::*;
+//!
+//! const SCOPED_CLASS: &str = prefix_classes!("app1-", classnames_concat!("btn", "btn-primary"));
+//! assert_eq!(SCOPED_CLASS, "app1-btn app1-btn-primary");
+//! ```
+//!
 //! Empty strings are handled gracefully:
 //! ```rust
 //! use classnames_const_rs::*;
@@ -60,7 +67,9 @@
 #[macro_export]
 macro_rules! classnames_concat {
     ($($x:expr),* $(,)?) => {
-        trim_format!(constcat::concat!($($x, " "),*))
+        $crate::trim_format!($crate::__private::constcat::concat!(
+            $($crate::__private::minify!($x), " "),*
+        ))
     };
 }
 
@@ -71,6 +80,10 @@ macro_rules! classnames_concat {
 /// - Remove leading and trailing whitespace
 /// - Handle various whitespace characters (spaces, tabs, newlines, etc.)
 ///
+/// Normalization is shared with `classnames-rs` through `classnames-core-rs`,
+/// so Unicode whitespace and multi-byte class names are treated identically
+/// at compile time and at runtime.
+///
 /// This macro is primarily used internally by `classnames_concat` and typically
 /// doesn't need to be called directly.
 ///
@@ -86,80 +99,111 @@ macro_rules! classnames_concat {
 macro_rules! trim_format {
     ($input:expr) => {{
         {
-            use ::constcat::core::mem;
-            use ::constcat::core::primitive::{str, u8};
-
-            const fn is_whitespace(c: u8) -> bool {
-                if let Some(ch) = char::from_u32(c as u32) {
-                    ch.is_whitespace()
-                } else {
-                    false
-                }
-            }
-
-            const SRC: &[u8] = $input.as_bytes();
-            const LEN: usize = $input.len();
-
-            const ARR: [u8; LEN] = {
-                let mut result = [0u8; LEN];
-                let mut pos = 0;
-                let mut i = 0;
-                let mut last_was_space = true; // Skip leading whitespace
-
-                // Copy and process characters
-                while i < LEN {
-                    if is_whitespace(SRC[i]) {
-                        if !last_was_space {
-                            result[pos] = b' ';
-                            pos += 1;
-                            last_was_space = true;
-                        }
-                    } else {
-                        result[pos] = SRC[i];
-                        pos += 1;
-                        last_was_space = false;
-                    }
-                    i += 1;
-                }
-
-                // Remove trailing space if present
-                if pos > 0 && result[pos - 1] == b' ' {
-                    pos -= 1;
-                }
-
-                // Fill remaining space with zeros
-                while pos < LEN {
-                    result[pos] = 0;
-                    pos += 1;
-                }
-
-                result
-            };
+            const __CLASSNAMES_SRC: &str = $input;
+            const __CLASSNAMES_LEN: usize = $crate::__private::normalized_len(__CLASSNAMES_SRC);
+            const __CLASSNAMES_FINAL: [u8; __CLASSNAMES_LEN] =
+                $crate::__private::normalize_to(__CLASSNAMES_SRC);
+            const __CLASSNAMES_OUT: &str = $crate::__private::as_str(&__CLASSNAMES_FINAL);
+            __CLASSNAMES_OUT
+        }
+    }};
+}
 
-            // Calculate actual length (up to first zero or end of array)
-            const REAL_LEN: usize = {
-                let mut len = 0;
-                while len < LEN && ARR[len] != 0 {
-                    len += 1;
-                }
-                len
-            };
+/// Prepends a prefix to every whitespace-separated class name at compile time
+///
+/// The input is normalized the same way as `trim_format!`, then each class name
+/// is rewritten as `prefix + class`. Empty input stays empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_const_rs::{classnames_concat, prefix_classes};
+///
+/// const SCOPED: &str = prefix_classes!("app1-", classnames_concat!("btn", "btn-primary"));
+/// assert_eq!(SCOPED, "app1-btn app1-btn-primary");
+///
+/// const MESSY: &str = prefix_classes!("tw-", "  flex \n items-center ");
+/// assert_eq!(MESSY, "tw-flex tw-items-center");
+/// ```
+#[macro_export]
+macro_rules! prefix_classes {
+    ($prefix:expr, $input:expr $(,)?) => {
+        $crate::affix_classes!($prefix, $input, "")
+    };
+}
 
-            // Create final fixed-size array for the result
-            const FINAL: [u8; REAL_LEN] = {
-                let mut result = [0u8; REAL_LEN];
-                let mut i = 0;
-                while i < REAL_LEN {
-                    result[i] = ARR[i];
-                    i += 1;
-                }
-                result
-            };
+/// Appends a suffix to every whitespace-separated class name at compile time
+///
+/// The input is normalized the same way as `trim_format!`, then each class name
+/// is rewritten as `class + suffix`. Empty input stays empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_const_rs::{classnames_concat, suffix_classes};
+///
+/// const SCOPED: &str = suffix_classes!("--app1", classnames_concat!("btn", "btn-primary"));
+/// assert_eq!(SCOPED, "btn--app1 btn-primary--app1");
+/// ```
+#[macro_export]
+macro_rules! suffix_classes {
+    ($suffix:expr, $input:expr $(,)?) => {
+        $crate::affix_classes!("", $input, $suffix)
+    };
+}
 
-            unsafe {
-                mem::transmute::<&[u8], &str>(&FINAL)
-            }
+/// Wraps every whitespace-separated class name with a prefix and a suffix
+///
+/// This macro backs `prefix_classes!` and `suffix_classes!`, and can be used
+/// directly when both are needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_const_rs::affix_classes;
+///
+/// const WRAPPED: &str = affix_classes!("app1-", "btn  active", "-v2");
+/// assert_eq!(WRAPPED, "app1-btn-v2 app1-active-v2");
+/// ```
+#[macro_export]
+macro_rules! affix_classes {
+    ($prefix:expr, $input:expr, $suffix:expr $(,)?) => {{
+        {
+            const __CLASSNAMES_SRC: &str = $input;
+            const __CLASSNAMES_PREFIX: &str = $prefix;
+            const __CLASSNAMES_SUFFIX: &str = $suffix;
+            const __CLASSNAMES_LEN: usize = $crate::__private::affixed_len(
+                __CLASSNAMES_SRC,
+                __CLASSNAMES_PREFIX,
+                __CLASSNAMES_SUFFIX,
+            );
+            const __CLASSNAMES_FINAL: [u8; __CLASSNAMES_LEN] = $crate::__private::affix_to(
+                __CLASSNAMES_SRC,
+                __CLASSNAMES_PREFIX,
+                __CLASSNAMES_SUFFIX,
+            );
+            const __CLASSNAMES_OUT: &str = $crate::__private::as_str(&__CLASSNAMES_FINAL);
+            __CLASSNAMES_OUT
         }
     }};
 }
 
+#[doc(hidden)]
+pub mod __private {
+    pub use classnames_core_rs::{affix_to, affixed_len, as_str, normalize_to, normalized_len};
+    pub use constcat;
+
+    #[cfg(not(feature = "minify"))]
+    pub use crate::__minify_identity as minify;
+    #[cfg(feature = "minify")]
+    pub use classnames_macros_rs::__minify as minify;
+}
+
+/// Passes a class name expression through unchanged when the `minify` feature is disabled
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __minify_identity {
+    ($x:expr) => {
+        $x
+    };
+}
@@ -1,4 +1,9 @@
-use classnames_const_rs::{classnames_concat, trim_format};
+// `const _: &'static str` assertions intentionally spell out the lifetime
+#![allow(clippy::redundant_static_lifetimes)]
+
+use classnames_const_rs::{
+    affix_classes, classnames_concat, prefix_classes, suffix_classes, trim_format,
+};
 
 const BASE_STYLE: &str = "btn";
 const SIZE_LG: &str = "lg";
@@ -155,3 +160,42 @@ fn test_classnames_concat_edge_cases() {
     const SINGLE: &str = classnames_concat!("single");
     assert_eq!(SINGLE, "single");
 }
+
+#[test]
+fn test_prefix_classes() {
+    const SCOPED: &str = prefix_classes!("app1-", classnames_concat!("btn", "btn-primary"));
+    assert_eq!(SCOPED, "app1-btn app1-btn-primary");
+
+    const FROM_CONST: &str = prefix_classes!("app1-", BASE_STYLE);
+    assert_eq!(FROM_CONST, "app1-btn");
+
+    const MESSY: &str = prefix_classes!("tw-", "\t flex \n  items-center  ");
+    assert_eq!(MESSY, "tw-flex tw-items-center");
+
+    // Verify type
+    const _: &'static str = SCOPED;
+}
+
+#[test]
+fn test_suffix_classes() {
+    const SCOPED: &str = suffix_classes!("--app1", classnames_concat!(BASE_STYLE, SIZE_LG));
+    assert_eq!(SCOPED, "btn--app1 lg--app1");
+
+    const SINGLE: &str = suffix_classes!("-x", "  only  ");
+    assert_eq!(SINGLE, "only-x");
+}
+
+#[test]
+fn test_affix_classes_edge_cases() {
+    const EMPTY: &str = prefix_classes!("app1-", "");
+    assert_eq!(EMPTY, "");
+
+    const ONLY_SPACES: &str = suffix_classes!("-x", "   ");
+    assert_eq!(ONLY_SPACES, "");
+
+    const NO_AFFIX: &str = affix_classes!("", " a  b ", "");
+    assert_eq!(NO_AFFIX, "a b");
+
+    const BOTH: &str = affix_classes!("app1-", "md:w-1/2 hover:bg-blue-500", "-v2");
+    assert_eq!(BOTH, "app1-md:w-1/2-v2 app1-hover:bg-blue-500-v2");
+}
@@ -0,0 +1,313 @@
+#![doc(html_root_url = "https://docs.rs/classnames-core-rs/0.1.0")]
+#![deny(missing_docs)]
+#![forbid(unsafe_code)]
+
+//! Shared class name normalization engine
+//!
+//! Both `classnames-rs` (runtime macros) and `classnames-const-rs` (compile-time
+//! macros) normalize class names through this crate, so they agree on:
+//!
+//! - What counts as whitespace (Unicode `White_Space`, same as [`char::is_whitespace`])
+//! - How class names are split into tokens
+//! - How tokens are joined (a single ASCII space, no leading or trailing whitespace)
+//!
+//! Every tokenizing primitive is a `const fn`, so the same code runs inside
+//! `const` items and at runtime.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use classnames_core_rs::{normalize, tokens};
+//!
+//! assert_eq!(normalize("  btn \n\t btn-primary "), "btn btn-primary");
+//! assert_eq!(tokens("a  b").collect::<Vec<_>>(), ["a", "b"]);
+//! ```
+//!
+//! Compile-time normalization:
+//!
+//! ```rust
+//! use classnames_core_rs::{normalize_to, normalized_len};
+//!
+//! const SRC: &str = "  hello    world  ";
+//! const LEN: usize = normalized_len(SRC);
+//! const BYTES: [u8; LEN] = normalize_to(SRC);
+//! assert_eq!(&BYTES, b"hello world");
+//! ```
+
+/// Decodes the character starting at byte `i` of a valid UTF-8 string
+///
+/// Returns the character and its encoded length in bytes.
+const fn decode_char(bytes: &[u8], i: usize) -> (char, usize) {
+    let b0 = bytes[i] as u32;
+    let (code, len) = if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xE0 {
+        (((b0 & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), 2)
+    } else if b0 < 0xF0 {
+        (
+            ((b0 & 0x0F) << 12)
+                | ((bytes[i + 1] as u32 & 0x3F) << 6)
+                | (bytes[i + 2] as u32 & 0x3F),
+            3,
+        )
+    } else {
+        (
+            ((b0 & 0x07) << 18)
+                | ((bytes[i + 1] as u32 & 0x3F) << 12)
+                | ((bytes[i + 2] as u32 & 0x3F) << 6)
+                | (bytes[i + 3] as u32 & 0x3F),
+            4,
+        )
+    };
+    match char::from_u32(code) {
+        Some(c) => (c, len),
+        None => (char::REPLACEMENT_CHARACTER, len),
+    }
+}
+
+/// Returns whether `c` separates class names
+///
+/// This is exactly [`char::is_whitespace`], which is also what
+/// [`str::split_whitespace`] uses.
+#[inline]
+pub const fn is_separator(c: char) -> bool {
+    c.is_whitespace()
+}
+
+/// Finds the next class name token in `input` at or after byte offset `from`
+///
+/// Returns the `(start, end)` byte range of the token, or `None` when only
+/// whitespace remains. `from` must lie on a character boundary.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_core_rs::next_token;
+///
+/// assert_eq!(next_token("  btn active", 0), Some((2, 5)));
+/// assert_eq!(next_token("  btn active", 5), Some((6, 12)));
+/// assert_eq!(next_token("  btn active", 12), None);
+/// ```
+pub const fn next_token(input: &str, from: usize) -> Option<(usize, usize)> {
+    let bytes = input.as_bytes();
+    let mut i = from;
+
+    // Skip leading whitespace
+    while i < bytes.len() {
+        let (c, len) = decode_char(bytes, i);
+        if !is_separator(c) {
+            break;
+        }
+        i += len;
+    }
+    if i >= bytes.len() {
+        return None;
+    }
+
+    let start = i;
+    while i < bytes.len() {
+        let (c, len) = decode_char(bytes, i);
+        if is_separator(c) {
+            break;
+        }
+        i += len;
+    }
+    Some((start, i))
+}
+
+/// Counts the class name tokens in `input`
+pub const fn token_count(input: &str) -> usize {
+    let mut count = 0;
+    let mut pos = 0;
+    while let Some((_, end)) = next_token(input, pos) {
+        count += 1;
+        pos = end;
+    }
+    count
+}
+
+/// Length in bytes of `input` once normalized
+pub const fn normalized_len(input: &str) -> usize {
+    affixed_len(input, "", "")
+}
+
+/// Length in bytes of `input` once normalized and every token wrapped with
+/// `prefix` and `suffix`
+pub const fn affixed_len(input: &str, prefix: &str, suffix: &str) -> usize {
+    let mut len = 0;
+    let mut count = 0;
+    let mut pos = 0;
+    while let Some((start, end)) = next_token(input, pos) {
+        len += end - start + prefix.len() + suffix.len();
+        count += 1;
+        pos = end;
+    }
+    if count > 0 {
+        len + count - 1
+    } else {
+        0
+    }
+}
+
+/// Copies `src` into `out` at `pos`, returning the new position
+const fn copy_bytes(out: &mut [u8], mut pos: usize, src: &[u8]) -> usize {
+    let mut i = 0;
+    while i < src.len() {
+        out[pos] = src[i];
+        pos += 1;
+        i += 1;
+    }
+    pos
+}
+
+/// Writes the normalized form of `input` to the start of `out`
+///
+/// Returns the number of bytes written, which is [`normalized_len`] of `input`.
+/// Panics if `out` is shorter than that.
+pub const fn normalize_into(input: &str, out: &mut [u8]) -> usize {
+    affix_into(input, "", "", out)
+}
+
+/// Writes the normalized form of `input`, with every token wrapped with
+/// `prefix` and `suffix`, to the start of `out`
+///
+/// Returns the number of bytes written, which is [`affixed_len`] of the same
+/// arguments. Panics if `out` is shorter than that.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_core_rs::affix_into;
+///
+/// let mut out = [0u8; 16];
+/// let len = affix_into(" a  b ", "x-", "", &mut out);
+/// assert_eq!(&out[..len], b"x-a x-b");
+/// ```
+pub const fn affix_into(input: &str, prefix: &str, suffix: &str, out: &mut [u8]) -> usize {
+    let bytes = input.as_bytes();
+    let mut pos = 0;
+    let mut cursor = 0;
+    while let Some((start, end)) = next_token(input, cursor) {
+        if pos > 0 {
+            out[pos] = b' ';
+            pos += 1;
+        }
+        pos = copy_bytes(out, pos, prefix.as_bytes());
+        let mut i = start;
+        while i < end {
+            out[pos] = bytes[i];
+            pos += 1;
+            i += 1;
+        }
+        pos = copy_bytes(out, pos, suffix.as_bytes());
+        cursor = end;
+    }
+    pos
+}
+
+/// Normalizes `input` into a fixed-size byte array at compile time
+///
+/// `N` must equal [`normalized_len`] of `input`; the result is valid UTF-8.
+pub const fn normalize_to<const N: usize>(input: &str) -> [u8; N] {
+    affix_to(input, "", "")
+}
+
+/// Normalizes `input` and wraps every token with `prefix` and `suffix` into a
+/// fixed-size byte array at compile time
+///
+/// `N` must equal [`affixed_len`] of the same arguments; the result is valid UTF-8.
+pub const fn affix_to<const N: usize>(input: &str, prefix: &str, suffix: &str) -> [u8; N] {
+    let mut out = [0u8; N];
+    if affix_into(input, prefix, suffix, &mut out) != N {
+        panic!("output length does not match the normalized length");
+    }
+    out
+}
+
+/// Converts bytes produced by [`normalize_to`] or [`affix_to`] back into a `&str`
+pub const fn as_str(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("normalized class names are not valid UTF-8"),
+    }
+}
+
+/// Iterator over the class name tokens of a string
+///
+/// Created by [`tokens`].
+#[derive(Debug, Clone)]
+pub struct Tokens<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = next_token(self.input, self.pos)?;
+        self.pos = end;
+        Some(&self.input[start..end])
+    }
+}
+
+/// Splits `input` into class name tokens
+pub const fn tokens(input: &str) -> Tokens<'_> {
+    Tokens { input, pos: 0 }
+}
+
+/// Buffers [`push_normalized`] can append to
+///
+/// Implemented for `String`; other string types implement it to share the
+/// tokenizer instead of re-splitting input themselves.
+pub trait ClassSink {
+    /// Returns `true` if nothing has been written yet
+    fn is_empty(&self) -> bool;
+
+    /// Appends `s` as is
+    fn push_str(&mut self, s: &str);
+}
+
+impl ClassSink for String {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        String::push_str(self, s);
+    }
+}
+
+/// Appends the normalized tokens of `input` to `out`
+///
+/// A single space is inserted before each token when `out` is not empty, so
+/// repeated calls build a normalized class list. Empty input leaves `out` untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_core_rs::push_normalized;
+///
+/// let mut classes = String::new();
+/// push_normalized(&mut classes, " btn ");
+/// push_normalized(&mut classes, "");
+/// push_normalized(&mut classes, "active\n large");
+/// assert_eq!(classes, "btn active large");
+/// ```
+pub fn push_normalized<S: ClassSink + ?Sized>(out: &mut S, input: &str) {
+    for token in tokens(input) {
+        if !out.is_empty() {
+            out.push_str(" ");
+        }
+        out.push_str(token);
+    }
+}
+
+/// Returns the normalized form of `input`
+pub fn normalize(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    push_normalized(&mut out, input);
+    out
+}
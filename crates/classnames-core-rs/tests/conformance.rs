@@ -0,0 +1,97 @@
+//! Conformance tests: `classnames-rs` (runtime) and `classnames-const-rs`
+//! (compile time) must produce identical output for the same input.
+
+use classnames_const_rs::{classnames_concat, prefix_classes, trim_format};
+use classnames_core_rs::{
+    affix_to, affixed_len, as_str, next_token, normalize, normalize_to, normalized_len,
+    token_count, tokens,
+};
+use cn::{choose, classnames, maybe, pretty_classname, when};
+
+/// Asserts that every normalization path agrees with `split_whitespace` for `$input`
+macro_rules! assert_conformance {
+    ($input:expr) => {{
+        const INPUT: &str = $input;
+        const CONST_TRIMMED: &str = trim_format!(INPUT);
+        const CONST_CONCAT: &str = classnames_concat!(INPUT);
+        let expected = INPUT.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        assert_eq!(CONST_TRIMMED, expected, "trim_format! on {:?}", INPUT);
+        assert_eq!(CONST_CONCAT, expected, "classnames_concat! on {:?}", INPUT);
+        assert_eq!(classnames!(INPUT), expected, "classnames! on {:?}", INPUT);
+        assert_eq!(
+            pretty_classname!(INPUT),
+            expected,
+            "pretty_classname! on {:?}",
+            INPUT
+        );
+        assert_eq!(normalize(INPUT), expected, "normalize on {:?}", INPUT);
+        assert_eq!(
+            tokens(INPUT).collect::<Vec<_>>(),
+            INPUT.split_whitespace().collect::<Vec<_>>()
+        );
+        assert_eq!(token_count(INPUT), INPUT.split_whitespace().count());
+    }};
+}
+
+#[test]
+fn test_ascii_whitespace() {
+    assert_conformance!("");
+    assert_conformance!("   ");
+    assert_conformance!("btn");
+    assert_conformance!("  btn   primary  ");
+    assert_conformance!("\t\n  hello  \r\n  world  \t");
+    assert_conformance!("a\x0Bb\x0Cc");
+}
+
+#[test]
+fn test_unicode_whitespace() {
+    assert_conformance!("btn\u{A0}active");
+    assert_conformance!("btn\u{85}active");
+    assert_conformance!("\u{3000}full\u{3000}width\u{3000}");
+    assert_conformance!("a\u{2028}b\u{2029}c\u{202F}d\u{205F}e\u{1680}f");
+    assert_conformance!("a\u{2000}\u{2001}\u{200A}b");
+}
+
+#[test]
+fn test_multibyte_class_names() {
+    // U+00C5 and U+00E0 encode with continuation bytes 0x85 and 0xA0, which
+    // must not be mistaken for whitespace
+    assert_conformance!("Åsa  bàr");
+    assert_conformance!("ё-класс   日本語 🚀rocket");
+    assert_conformance!("md:w-1/2 hover:bg-blue-500\u{A0}focus:outline-none");
+}
+
+#[test]
+fn test_multi_argument_agreement() {
+    const CONST_RESULT: &str = classnames_concat!("  header ", "\u{A0}main\u{2003}", "", "Å");
+    let runtime_result = classnames!("  header ", "\u{A0}main\u{2003}", "", "Å");
+    assert_eq!(CONST_RESULT, runtime_result);
+    assert_eq!(runtime_result, "header main Å");
+}
+
+#[test]
+fn test_helper_macros_normalize() {
+    let messy = " a \u{A0} b ";
+    assert_eq!(choose!(true, messy, ""), "a b");
+    assert_eq!(when!(true, messy), "a b");
+    assert_eq!(maybe!(Some(messy)), "a b");
+    assert_eq!(classnames!((true, messy)), "a b");
+    assert_eq!(classnames!((false, "x", messy)), "a b");
+}
+
+#[test]
+fn test_const_engine() {
+    const SRC: &str = " btn\u{3000}Åsa ";
+    const LEN: usize = normalized_len(SRC);
+    const BYTES: [u8; LEN] = normalize_to(SRC);
+    assert_eq!(as_str(&BYTES), normalize(SRC));
+
+    const AFFIX_LEN: usize = affixed_len(SRC, "p-", "-s");
+    const AFFIXED: [u8; AFFIX_LEN] = affix_to(SRC, "p-", "-s");
+    assert_eq!(as_str(&AFFIXED), "p-btn-s p-Åsa-s");
+    assert_eq!(prefix_classes!("p-", SRC), "p-btn p-Åsa");
+
+    assert_eq!(next_token(SRC, 0), Some((1, 4)));
+    assert_eq!(next_token("", 0), None);
+}
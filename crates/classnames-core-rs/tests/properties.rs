@@ -0,0 +1,276 @@
+//! Property tests: random argument lists must produce the same class string as
+//! a reference model built on `str::split_whitespace`.
+//!
+//! `classnames_concat!` only takes constants, so its pipeline (join the
+//! arguments with spaces, then `normalized_len` + `normalize_into`) is replayed
+//! at runtime through the same `const fn`s. The macro itself is expanded over
+//! class strings that a `const fn` generates from fixed seeds.
+
+use classnames_const_rs::classnames_concat;
+use classnames_core_rs::{
+    affix_into, affixed_len, as_str, normalize, normalize_into, normalized_len, token_count, tokens,
+};
+use cn::{choose, classnames, maybe, pretty_classname, when};
+use proptest::prelude::*;
+
+/// Whitespace the generated class strings are padded with, including Unicode
+/// separators and characters whose UTF-8 encoding shares bytes with them
+const WHITESPACE: &[char] = &[
+    ' ', '\t', '\n', '\r', '\x0B', '\x0C', '\u{85}', '\u{A0}', '\u{1680}', '\u{2000}', '\u{200A}',
+    '\u{2028}', '\u{2029}', '\u{202F}', '\u{205F}', '\u{3000}',
+];
+
+/// Reference model: every token of every included value, joined by one space
+fn model(values: &[&str]) -> String {
+    values
+        .iter()
+        .flat_map(|value| value.split_whitespace())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Normalizes `input` the way `trim_format!` does, with the const primitives
+fn const_normalize(input: &str) -> String {
+    let mut out = vec![0; normalized_len(input)];
+    assert_eq!(normalize_into(input, &mut out), out.len());
+    as_str(&out).to_string()
+}
+
+/// Replays `classnames_concat!(values...)`: each value followed by a space
+fn const_concat(values: &[&str]) -> String {
+    let joined: String = values.iter().flat_map(|value| [*value, " "]).collect();
+    const_normalize(&joined)
+}
+
+/// Pieces the seeded class strings are made of
+const PIECES: &[&str] = &[
+    " ",
+    "\t",
+    "\n",
+    "\r",
+    "\x0B",
+    "\x0C",
+    "\u{85}",
+    "\u{A0}",
+    "\u{1680}",
+    "\u{2000}",
+    "\u{200A}",
+    "\u{2028}",
+    "\u{2029}",
+    "\u{202F}",
+    "\u{205F}",
+    "\u{3000}",
+    "btn",
+    "a",
+    "0",
+    "-",
+    "_",
+    ":",
+    "[",
+    "]",
+    "/",
+    "Å",
+    "à",
+    "日本",
+    "\u{1F600}",
+];
+
+/// A class string of `N` bytes made of `PIECES`, picked by an xorshift
+/// generator started from `seed`
+const fn generate<const N: usize>(seed: u64) -> [u8; N] {
+    let mut out = [0; N];
+    let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+    let mut len = 0;
+    while len < N {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let piece = PIECES[(state % PIECES.len() as u64) as usize].as_bytes();
+        // Single-byte pieces always fit, so the string is eventually filled
+        if len + piece.len() <= N {
+            let mut i = 0;
+            while i < piece.len() {
+                out[len + i] = piece[i];
+                i += 1;
+            }
+            len += piece.len();
+        }
+    }
+    out
+}
+
+/// Expands `classnames_concat!` and `classnames!` over two class strings
+/// generated from each seed, around a literal, along with the model's result
+macro_rules! seeded_concat {
+    ($($seed:literal),* $(,)?) => {
+        [$({
+            const FIRST: [u8; $seed % 23] = generate($seed);
+            const SECOND: [u8; $seed % 17 + 3] = generate($seed + 1000);
+            const A: &str = as_str(&FIRST);
+            const B: &str = as_str(&SECOND);
+            (
+                classnames_concat!(A, " btn\u{3000}", B, ""),
+                classnames!(A, " btn\u{3000}", B, ""),
+                model(&[A, " btn\u{3000}", B, ""]),
+            )
+        }),*]
+    };
+}
+
+#[test]
+fn classnames_concat_matches_model() {
+    let cases = seeded_concat!(
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+        26, 27, 28, 29, 30, 31, 32,
+    );
+    for (concat, runtime, expected) in cases {
+        assert_eq!(concat, expected);
+        assert_eq!(runtime, expected);
+    }
+}
+
+fn whitespace() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::sample::select(WHITESPACE), 0..4)
+        .prop_map(|chars| chars.into_iter().collect())
+}
+
+fn class_name() -> impl Strategy<Value = String> {
+    "[a-z0-9:_\\-\\[\\]/ÅàÀ日本\u{1F600}]{1,8}"
+}
+
+/// Class names with arbitrary whitespace around and between them, or any string
+fn class_string() -> impl Strategy<Value = String> {
+    prop_oneof![
+        4 => (prop::collection::vec((whitespace(), class_name()), 0..5), whitespace()).prop_map(
+            |(parts, trailing)| {
+                let mut out = String::new();
+                for (space, class) in parts {
+                    out.push_str(&space);
+                    out.push_str(&class);
+                }
+                out.push_str(&trailing);
+                out
+            }
+        ),
+        1 => any::<String>(),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn normalize_matches_model(input in class_string()) {
+        let expected = model(&[&input]);
+        prop_assert_eq!(normalize(&input), expected.as_str());
+        prop_assert_eq!(const_normalize(&input), expected.as_str());
+        prop_assert_eq!(tokens(&input).collect::<Vec<_>>(), input.split_whitespace().collect::<Vec<_>>());
+        prop_assert_eq!(token_count(&input), input.split_whitespace().count());
+        prop_assert_eq!(classnames!(&input), expected.as_str());
+        prop_assert_eq!(pretty_classname!(&input), expected.as_str());
+    }
+
+    #[test]
+    fn affixed_len_matches_model(
+        input in class_string(),
+        prefix in "[a-z:\\-]{0,4}",
+        suffix in "[a-z\\-]{0,4}",
+    ) {
+        let expected = input
+            .split_whitespace()
+            .map(|token| format!("{}{}{}", prefix, token, suffix))
+            .collect::<Vec<_>>()
+            .join(" ");
+        prop_assert_eq!(affixed_len(&input, &prefix, &suffix), expected.len());
+        let mut out = vec![0; expected.len()];
+        prop_assert_eq!(affix_into(&input, &prefix, &suffix, &mut out), expected.len());
+        prop_assert_eq!(as_str(&out), expected.as_str());
+    }
+
+    #[test]
+    fn conditions_match_model(
+        values in prop::collection::vec(class_string(), 6),
+        conditions in prop::collection::vec(any::<bool>(), 4),
+        optional in prop::option::of(class_string()),
+    ) {
+        let [a, b, c, d, e, f] = [&values[0], &values[1], &values[2], &values[3], &values[4], &values[5]];
+        let [c1, c2, c3, c4] = [conditions[0], conditions[1], conditions[2], conditions[3]];
+        let optional = optional.as_deref();
+
+        let result = classnames!(
+            a,
+            (c1, b.as_str()),
+            maybe!(optional),
+            when!(c2, c),
+            (c3, d.as_str(), e.as_str()),
+            choose!(c4, f, a),
+            (c1, [b.as_str(), maybe!(optional), (c2, f.as_str())], [e.as_str()])
+        );
+
+        let mut included = vec![a.as_str()];
+        if c1 {
+            included.push(b);
+        }
+        included.extend(optional);
+        if c2 {
+            included.push(c);
+        }
+        included.push(if c3 { d } else { e });
+        included.push(if c4 { f } else { a });
+        if c1 {
+            included.push(b);
+            included.extend(optional);
+            if c2 {
+                included.push(f);
+            }
+        } else {
+            included.push(e);
+        }
+        let expected = model(&included);
+
+        prop_assert_eq!(const_concat(&included), expected.as_str());
+        prop_assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn match_arms_match_model(
+        values in prop::collection::vec(class_string(), 3),
+        selector in 0u8..4,
+        optional in prop::option::of(class_string()),
+    ) {
+        let optional = optional.as_deref();
+        let result = classnames!(
+            &values[0],
+            match selector {
+                0 => values[1].as_str(),
+                1 => optional,
+                2 => [values[1].as_str(), values[2].as_str()],
+                _ => (),
+            }
+        );
+
+        let mut included = vec![values[0].as_str()];
+        match selector {
+            0 => included.push(&values[1]),
+            1 => included.extend(optional),
+            2 => included.extend([values[1].as_str(), values[2].as_str()]),
+            _ => {}
+        }
+        prop_assert_eq!(result, model(&included));
+    }
+
+    #[test]
+    fn const_concat_matches_classnames(values in prop::collection::vec(class_string(), 0..6)) {
+        let values: Vec<&str> = values.iter().map(String::as_str).collect();
+        let mut runtime = String::new();
+        for value in &values {
+            let value = classnames!(value);
+            if !value.is_empty() {
+                if !runtime.is_empty() {
+                    runtime.push(' ');
+                }
+                runtime.push_str(&value);
+            }
+        }
+        prop_assert_eq!(const_concat(&values), runtime.as_str());
+        prop_assert_eq!(runtime, model(&values));
+    }
+}
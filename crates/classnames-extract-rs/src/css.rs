@@ -0,0 +1,275 @@
+//! Minimal CSS scanner collecting the class selectors of a stylesheet
+//!
+//! Only selector preludes are inspected: declarations, comments, strings,
+//! attribute selectors and the bodies of `@keyframes`, `@font-face` and similar
+//! at-rules are skipped. Escaped class names such as `md\:w-1\/2` are unescaped
+//! to the class they match (`md:w-1/2`). Nested rules are supported.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::Error;
+
+/// A class selector found in a stylesheet
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CssClass {
+    /// The unescaped class name
+    pub class: String,
+    /// Stylesheet path, empty for sources passed to [`parse_css`] with an empty path
+    pub file: PathBuf,
+    /// 1-based line of the selector
+    pub line: usize,
+    /// 1-based column of the `.` starting the class selector
+    pub column: usize,
+}
+
+/// At-rules whose blocks contain no class selectors
+const OPAQUE_AT_RULES: &[&str] = &[
+    "keyframes",
+    "-webkit-keyframes",
+    "-moz-keyframes",
+    "font-face",
+    "page",
+    "property",
+    "counter-style",
+    "font-feature-values",
+];
+
+struct Scanner<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+    file: &'a Path,
+    classes: Vec<CssClass>,
+}
+
+impl Scanner<'_> {
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Skips a comment if one starts here
+    fn skip_comment(&mut self) -> bool {
+        if self.peek(0) == Some('/') && self.peek(1) == Some('*') {
+            self.bump();
+            self.bump();
+            while let Some(c) = self.bump() {
+                if c == '*' && self.peek(0) == Some('/') {
+                    self.bump();
+                    break;
+                }
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Skips a quoted string starting at the current quote character
+    fn skip_string(&mut self) {
+        let quote = self.bump();
+        while let Some(c) = self.bump() {
+            if c == '\\' {
+                self.bump();
+            } else if Some(c) == quote {
+                break;
+            }
+        }
+    }
+
+    /// Skips to the end of the current block, including nested blocks
+    fn skip_block(&mut self) {
+        let mut depth = 1;
+        while let Some(c) = self.peek(0) {
+            if self.skip_comment() {
+                continue;
+            }
+            match c {
+                '"' | '\'' => self.skip_string(),
+                '{' => {
+                    depth += 1;
+                    self.bump();
+                }
+                '}' => {
+                    self.bump();
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    /// Scans rules until the end of the current block (or input)
+    fn scan_rules(&mut self) {
+        loop {
+            // Start of a prelude, declaration or statement
+            let start = self.pos;
+            let mut prelude_classes = Vec::new();
+            let mut bracket_depth = 0;
+            loop {
+                if self.skip_comment() {
+                    continue;
+                }
+                let Some(c) = self.peek(0) else {
+                    return;
+                };
+                match c {
+                    '"' | '\'' => self.skip_string(),
+                    '[' => {
+                        bracket_depth += 1;
+                        self.bump();
+                    }
+                    ']' => {
+                        bracket_depth -= 1;
+                        self.bump();
+                    }
+                    '\\' => {
+                        self.bump();
+                        self.bump();
+                    }
+                    '.' if bracket_depth == 0 => {
+                        let (line, column) = (self.line, self.column);
+                        self.bump();
+                        let class = self.read_ident();
+                        if !class.is_empty() {
+                            prelude_classes.push(CssClass {
+                                class,
+                                file: self.file.to_path_buf(),
+                                line,
+                                column,
+                            });
+                        }
+                    }
+                    ';' => {
+                        // Declaration or statement at-rule, never a selector
+                        self.bump();
+                        break;
+                    }
+                    '}' => {
+                        self.bump();
+                        return;
+                    }
+                    '{' => {
+                        let prelude: String = self.chars[start..self.pos].iter().collect();
+                        self.bump();
+                        let prelude = prelude.trim();
+                        if let Some(at_rule) = prelude.strip_prefix('@') {
+                            let name = at_rule
+                                .split(|c: char| c.is_whitespace() || c == '(')
+                                .next()
+                                .unwrap_or("");
+                            if OPAQUE_AT_RULES.contains(&name.to_ascii_lowercase().as_str()) {
+                                self.skip_block();
+                            } else {
+                                self.scan_rules();
+                            }
+                        } else {
+                            self.classes.append(&mut prelude_classes);
+                            self.scan_rules();
+                        }
+                        break;
+                    }
+                    _ => {
+                        self.bump();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reads a CSS identifier, unescaping `\` sequences
+    fn read_ident(&mut self) -> String {
+        let mut ident = String::new();
+        // `.5em` style numbers are not class selectors, but escaped digits (`.\31 0`) are
+        if self.peek(0).is_some_and(|c| c.is_ascii_digit()) {
+            return ident;
+        }
+        while let Some(c) = self.peek(0) {
+            if c == '\\' {
+                self.bump();
+                let mut hex = String::new();
+                while hex.len() < 6 && self.peek(0).is_some_and(|c| c.is_ascii_hexdigit()) {
+                    hex.push(self.bump().expect("peeked"));
+                }
+                if hex.is_empty() {
+                    if let Some(escaped) = self.bump() {
+                        ident.push(escaped);
+                    }
+                } else {
+                    // A single whitespace terminates a hex escape
+                    if self.peek(0).is_some_and(|c| c.is_whitespace()) {
+                        self.bump();
+                    }
+                    let code = u32::from_str_radix(&hex, 16).unwrap_or(0xFFFD);
+                    ident.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+            } else if c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii() {
+                ident.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        ident
+    }
+}
+
+/// Collects every class selector of a stylesheet, in source order
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_extract_rs::css::parse_css;
+/// use std::path::Path;
+///
+/// let css = ".btn, .card > .title:hover { color: red; }\n.md\\:w-1\\/2 { width: 50%; }";
+/// let classes: Vec<_> = parse_css(Path::new("app.css"), css)
+///     .into_iter()
+///     .map(|c| (c.class, c.line))
+///     .collect();
+/// assert_eq!(
+///     classes,
+///     [
+///         ("btn".to_string(), 1),
+///         ("card".to_string(), 1),
+///         ("title".to_string(), 1),
+///         ("md:w-1/2".to_string(), 2)
+///     ]
+/// );
+/// ```
+pub fn parse_css(file: &Path, source: &str) -> Vec<CssClass> {
+    let mut scanner = Scanner {
+        chars: source.chars().collect(),
+        pos: 0,
+        line: 1,
+        column: 1,
+        file,
+        classes: Vec::new(),
+    };
+    scanner.scan_rules();
+    scanner.classes
+}
+
+/// Reads a stylesheet and collects its class selectors
+pub fn read_css(path: &Path) -> Result<Vec<CssClass>, Error> {
+    let source = fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+    Ok(parse_css(path, &source))
+}
@@ -0,0 +1,1064 @@
+#![doc(html_root_url = "https://docs.rs/classnames-extract-rs/0.1.0")]
+#![deny(missing_docs)]
+
+//! Extracts literal class names from `classnames-rs` and `classnames-const-rs`
+//! macro invocations
+//!
+//! Tailwind's content scanner only sees whole strings, so classes assembled
+//! through `classnames!` arguments or constants are easy to miss. This crate
+//! parses Rust sources with `syn`, walks every `classnames!`,
+//! `cow_classnames!`, `small_classnames!`, `reactive_classnames!`, `choose!`,
+//! `when!`, `maybe!`, `theme!`, `pretty_classname!`, `classnames_concat!`,
+//! `trim_format!`, `prefix_classes!`, `suffix_classes!` and `affix_classes!`
+//! invocation (including ones nested in other macros such as `view!` or
+//! `html!`), and collects every class token that can end up in the output.
+//! `tokens!` values are IDs, `part` names or keywords rather than classes, so
+//! they are not collected.
+//!
+//! String constants passed by name, like `PRIMARY` or `theme::PRIMARY`, are
+//! resolved against `const` and `static` items found in the scanned sources,
+//! matching the module they are declared in. A name that several modules
+//! declare and the reference doesn't single out is reported by
+//! [`Extractor::ambiguous_constants`] rather than guessed.
+//!
+//! Design tokens are read from the tokens file of the crate a source belongs
+//! to (`tokens.toml` next to its `Cargo.toml`, or `CLASSNAMES_TOKENS`), so
+//! classes used through `@button.primary` references and through the constants
+//! `design_tokens!` generates, like `tokens::button::PRIMARY`, are collected
+//! too. References the file doesn't define are skipped.
+//!
+//! The [`css`] module and [`UsageReport`] compare those classes against
+//! stylesheets to find dead selectors and classes without styles, and the
+//! [`minify`] module turns them into the class name manifest of the
+//! `classnames-rs` `minify` feature.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use classnames_extract_rs::{classes, extract_source};
+//!
+//! let source = r#"
+//!     const BASE: &str = "btn  rounded";
+//!     fn class(active: bool) -> String {
+//!         classnames!(BASE, (active, "bg-blue-500", "bg-gray-200"), when!(active, "ring"))
+//!     }
+//! "#;
+//! let found = extract_source(source).unwrap();
+//! let classes = classes(&found);
+//! assert_eq!(
+//!     classes.into_iter().collect::<Vec<_>>(),
+//!     ["bg-blue-500", "bg-gray-200", "btn", "ring", "rounded"]
+//! );
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use css::CssClass;
+use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream, TokenTree};
+use syn::{
+    parse::{ParseStream, Parser},
+    punctuated::Punctuated,
+    visit::Visit,
+    Block, Expr, ExprLit, FieldValue, Lit, LitStr, Macro, Stmt, Token,
+};
+
+pub mod css;
+pub mod minify;
+
+/// A class name found in a macro invocation
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ClassOccurrence {
+    /// The class name token
+    pub class: String,
+    /// Source file, empty for sources passed to [`extract_source`]
+    pub file: PathBuf,
+    /// 1-based line of the literal or constant reference
+    pub line: usize,
+    /// 1-based column of the literal or constant reference
+    pub column: usize,
+    /// Name of the macro the class was passed to, without the `!`
+    pub macro_name: String,
+}
+
+impl fmt::Display for ClassOccurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} ({}!)",
+            self.file.display(),
+            self.line,
+            self.column,
+            self.class,
+            self.macro_name
+        )
+    }
+}
+
+/// Errors raised while reading or parsing sources
+#[derive(Debug)]
+pub enum Error {
+    /// A file or directory could not be read
+    Io(PathBuf, io::Error),
+    /// A file is not valid Rust
+    Parse(PathBuf, syn::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            Error::Parse(path, err) => {
+                let start = err.span().start();
+                write!(
+                    f,
+                    "{}:{}:{}: {}",
+                    path.display(),
+                    start.line,
+                    start.column + 1,
+                    err
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, err) => Some(err),
+            Error::Parse(_, err) => Some(err),
+        }
+    }
+}
+
+/// Macros whose arguments are scanned for class names
+const MACROS: &[&str] = &[
+    "classnames",
+    "cow_classnames",
+    "small_classnames",
+    "reactive_classnames",
+    "choose",
+    "when",
+    "maybe",
+    "theme",
+    "pretty_classname",
+    "classnames_concat",
+    "trim_format",
+    "prefix_classes",
+    "suffix_classes",
+    "affix_classes",
+];
+
+/// Returns whether `name` is one of the macros this crate understands
+fn is_known_macro(name: &str) -> bool {
+    MACROS.contains(&name)
+}
+
+/// Macros whose literal class names the `minify` feature rewrites, along with
+/// the `when!`, `choose!`, `maybe!` and `theme!` calls nested in them
+const MINIFIED_MACROS: &[&str] = &[
+    "classnames",
+    "cow_classnames",
+    "small_classnames",
+    "reactive_classnames",
+    "pretty_classname",
+    "classnames_concat",
+];
+
+/// A string `const` or `static` item
+#[derive(Debug)]
+struct Constant {
+    file: PathBuf,
+    /// Module path, from the crate root
+    module: Vec<String>,
+    value: String,
+}
+
+/// A constant referenced by name, resolved once every source has been scanned
+#[derive(Debug)]
+struct PendingConstant {
+    /// Modules the reference names before the constant, e.g. `theme` in `theme::PRIMARY`
+    qualifier: Vec<String>,
+    name: String,
+    file: PathBuf,
+    /// Module the reference appears in
+    module: Vec<String>,
+    location: (usize, usize),
+    macro_name: String,
+    affix: (String, String),
+}
+
+impl PendingConstant {
+    /// The constant as written, e.g. `theme::PRIMARY`
+    fn path(&self) -> String {
+        let mut path = self.qualifier.join("::");
+        if !path.is_empty() {
+            path.push_str("::");
+        }
+        path + &self.name
+    }
+}
+
+/// A constant reference that matches several constants with different values
+///
+/// Its classes are left out of [`Extractor::finish`], since picking one of the
+/// constants could report the wrong classes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AmbiguousConstant {
+    /// The reference as written, e.g. `PRIMARY` or `theme::PRIMARY`
+    pub name: String,
+    /// Source file of the reference
+    pub file: PathBuf,
+    /// 1-based line of the reference
+    pub line: usize,
+    /// 1-based column of the reference
+    pub column: usize,
+    /// Module paths of the matching constants, e.g. `admin::theme::PRIMARY`
+    pub candidates: Vec<String>,
+}
+
+impl fmt::Display for AmbiguousConstant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: `{}` could be any of {}; qualify it with its module",
+            self.file.display(),
+            self.line,
+            self.column,
+            self.name,
+            self.candidates.join(", ")
+        )
+    }
+}
+
+/// Default tokens file, relative to the crate root, as in `classnames-rs`
+const DEFAULT_TOKENS_FILE: &str = "tokens.toml";
+
+/// Environment variable overriding `DEFAULT_TOKENS_FILE`, as in `classnames-rs`
+const TOKENS_FILE_VAR: &str = "CLASSNAMES_TOKENS";
+
+/// Class lists of a tokens file by dotted path, normalized
+type Tokens = BTreeMap<String, String>;
+
+/// Flattens a tokens table into `out`
+fn flatten_tokens(table: toml::Table, prefix: &str, out: &mut Tokens) -> Result<(), String> {
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            toml::Value::String(classes) => {
+                out.insert(path, classnames_core_rs::normalize(&classes));
+            }
+            toml::Value::Array(items) => {
+                let mut classes = String::new();
+                for item in items {
+                    let item = item
+                        .as_str()
+                        .ok_or_else(|| format!("design token `{}` must only list strings", path))?;
+                    classnames_core_rs::push_normalized(&mut classes, item);
+                }
+                out.insert(path, classes);
+            }
+            toml::Value::Table(table) => flatten_tokens(table, &path, out)?,
+            _ => {
+                return Err(format!(
+                    "design token `{}` must be a string, an array of strings or a table",
+                    path
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Reads a tokens file; a missing file has no tokens
+fn read_tokens(path: &Path) -> Result<Tokens, String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Tokens::new()),
+        Err(err) => return Err(format!("{}: {}", path.display(), err)),
+    };
+    let table: toml::Table = content
+        .parse()
+        .map_err(|e| format!("invalid design tokens {}: {}", path.display(), e))?;
+    let mut tokens = Tokens::new();
+    flatten_tokens(table, "", &mut tokens).map_err(|e| format!("{} in {}", e, path.display()))?;
+    Ok(tokens)
+}
+
+/// Directory of the nearest `Cargo.toml` above `file`, or the current directory
+fn crate_root(file: &Path) -> PathBuf {
+    file.ancestors()
+        .skip(1)
+        .find(|dir| dir.join("Cargo.toml").is_file())
+        .unwrap_or(Path::new(""))
+        .to_path_buf()
+}
+
+/// Module path of a source file, from the directories below `src`
+///
+/// `src/lib.rs` and `src/main.rs` are the crate root, `src/a/mod.rs` is `a` and
+/// `src/a/b.rs` is `a::b`. Files outside a `src` directory are treated like
+/// files directly in it.
+fn file_module(file: &Path) -> Vec<String> {
+    let components: Vec<String> = file
+        .with_extension("")
+        .components()
+        .filter_map(|c| c.as_os_str().to_str().map(str::to_string))
+        .collect();
+    let start = components
+        .iter()
+        .rposition(|c| c == "src")
+        .map_or(components.len().saturating_sub(1), |i| i + 1);
+    let mut module = components[start..].to_vec();
+    if matches!(
+        module.last().map(String::as_str),
+        Some("lib" | "main" | "mod")
+    ) {
+        module.pop();
+    }
+    module
+}
+
+/// Collects class names across any number of source files
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_extract_rs::Extractor;
+/// use std::path::Path;
+///
+/// let mut extractor = Extractor::new();
+/// extractor
+///     .add_source(Path::new("a.rs"), r#"const PRIMARY: &str = "btn-primary";"#)
+///     .unwrap();
+/// extractor
+///     .add_source(Path::new("b.rs"), r#"fn f() { classnames!("btn", PRIMARY); }"#)
+///     .unwrap();
+/// let found = extractor.finish();
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[1].class, "btn-primary");
+/// assert_eq!(found[1].file, Path::new("b.rs"));
+/// ```
+#[derive(Debug, Default)]
+pub struct Extractor {
+    occurrences: Vec<ClassOccurrence>,
+    /// Constants by name
+    constants: HashMap<String, Vec<Constant>>,
+    pending: Vec<PendingConstant>,
+    /// Tokens file `@` references resolve against, instead of the crate's own
+    tokens_file: Option<PathBuf>,
+    /// Tokens files read so far
+    tokens: HashMap<PathBuf, Tokens>,
+    /// Classes that reach the output as written in crates that minify: the
+    /// values of constants and helpers outside a class list
+    unminified: BTreeSet<String>,
+}
+
+impl Extractor {
+    /// Creates an empty extractor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves `@` references and argument-less `design_tokens!` calls
+    /// against `path` instead of the tokens file of each source's crate
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use classnames_extract_rs::{classes, Extractor};
+    /// use std::path::Path;
+    ///
+    /// let dir = std::env::temp_dir().join("classnames-extract-doc-tokens");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("tokens.toml"), "[button]\nprimary = \"btn btn-primary\"").unwrap();
+    ///
+    /// let mut extractor = Extractor::new();
+    /// extractor.set_tokens_file(&dir.join("tokens.toml"));
+    /// extractor
+    ///     .add_source(Path::new("a.rs"), "fn f() { classnames!(@button.primary); }")
+    ///     .unwrap();
+    /// let found = extractor.finish();
+    /// assert_eq!(classes(&found).into_iter().collect::<Vec<_>>(), ["btn", "btn-primary"]);
+    /// ```
+    pub fn set_tokens_file(&mut self, path: &Path) {
+        self.tokens_file = Some(path.to_path_buf());
+    }
+
+    /// Path of the tokens file for `file`: `explicit` (from `design_tokens!`)
+    /// relative to its crate root, else the configured or default file
+    fn tokens_path(&self, file: &Path, explicit: Option<&str>) -> PathBuf {
+        match (explicit, &self.tokens_file) {
+            (Some(explicit), _) => crate_root(file).join(explicit),
+            (None, Some(path)) => path.clone(),
+            (None, None) => {
+                let name = std::env::var(TOKENS_FILE_VAR)
+                    .unwrap_or_else(|_| DEFAULT_TOKENS_FILE.to_string());
+                crate_root(file).join(name)
+            }
+        }
+    }
+
+    /// Tokens of the file at `path`, read once
+    fn tokens(&mut self, path: PathBuf) -> Result<&Tokens, String> {
+        if !self.tokens.contains_key(&path) {
+            let tokens = read_tokens(&path)?;
+            self.tokens.insert(path.clone(), tokens);
+        }
+        Ok(&self.tokens[&path])
+    }
+
+    /// Scans Rust source code, attributing occurrences to `file`
+    ///
+    /// An unreadable or invalid tokens file is reported as an error spanning
+    /// the whole source.
+    pub fn add_source(&mut self, file: &Path, source: &str) -> syn::Result<()> {
+        let ast = syn::parse_file(source)?;
+        let mut visitor = SourceVisitor {
+            extractor: self,
+            file,
+            module: file_module(file),
+            minified: false,
+            error: None,
+        };
+        visitor.visit_file(&ast);
+        match visitor.error {
+            Some(message) => Err(syn::Error::new(Span::call_site(), message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Reads and scans a single `.rs` file
+    pub fn add_file(&mut self, path: &Path) -> Result<(), Error> {
+        let source = fs::read_to_string(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        self.add_source(path, &source)
+            .map_err(|e| Error::Parse(path.to_path_buf(), e))
+    }
+
+    /// Scans a file, or every `.rs` file below a directory
+    ///
+    /// Hidden directories and `target` directories are skipped.
+    pub fn add_path(&mut self, path: &Path) -> Result<(), Error> {
+        if path.is_file() {
+            return self.add_file(path);
+        }
+
+        let entries = fs::read_dir(path).map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        let mut paths = entries
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::Io(path.to_path_buf(), e))?;
+        paths.sort();
+
+        for entry in paths {
+            let name = entry.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if entry.is_dir() {
+                if !name.starts_with('.') && name != "target" {
+                    self.add_path(&entry)?;
+                }
+            } else if name.ends_with(".rs") {
+                self.add_file(&entry)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Constants matching `pending`, narrowed down to the closest module
+    ///
+    /// A qualified reference matches constants whose module path ends with the
+    /// qualifier. An unqualified one prefers a constant in its own module, then
+    /// in its own file, then anywhere.
+    fn candidates(&self, pending: &PendingConstant) -> Vec<&Constant> {
+        let Some(constants) = self.constants.get(&pending.name) else {
+            return Vec::new();
+        };
+        let matching: Vec<&Constant> = constants
+            .iter()
+            .filter(|c| c.module.ends_with(&pending.qualifier))
+            .collect();
+        if !pending.qualifier.is_empty() {
+            return matching;
+        }
+        let same_module: Vec<&Constant> = matching
+            .iter()
+            .copied()
+            .filter(|c| c.module == pending.module)
+            .collect();
+        if !same_module.is_empty() {
+            return same_module;
+        }
+        let same_file: Vec<&Constant> = matching
+            .iter()
+            .copied()
+            .filter(|c| c.file == pending.file)
+            .collect();
+        if !same_file.is_empty() {
+            return same_file;
+        }
+        matching
+    }
+
+    /// Value of the constant `pending` refers to, or `Err` with the candidates
+    /// if it matches constants with different values
+    fn resolve(&self, pending: &PendingConstant) -> Result<Option<&str>, Vec<&Constant>> {
+        let candidates = self.candidates(pending);
+        match candidates.first() {
+            None => Ok(None),
+            Some(first) if candidates.iter().all(|c| c.value == first.value) => {
+                Ok(Some(&first.value))
+            }
+            Some(_) => Err(candidates),
+        }
+    }
+
+    /// Constant references that match several constants with different values
+    ///
+    /// Call this before [`finish`](Extractor::finish), which skips them.
+    pub fn ambiguous_constants(&self) -> Vec<AmbiguousConstant> {
+        self.pending
+            .iter()
+            .filter_map(|pending| {
+                let candidates = self.resolve(pending).err()?;
+                Some(AmbiguousConstant {
+                    name: pending.path(),
+                    file: pending.file.clone(),
+                    line: pending.location.0,
+                    column: pending.location.1,
+                    candidates: candidates
+                        .iter()
+                        .map(|c| {
+                            let mut path = c.module.clone();
+                            path.push(pending.name.clone());
+                            path.join("::")
+                        })
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    /// Resolves constant references and returns every occurrence, sorted by
+    /// file, position and class
+    ///
+    /// References to constants that were not found in any scanned source, and
+    /// [ambiguous](Extractor::ambiguous_constants) ones, are dropped.
+    pub fn finish(self) -> Vec<ClassOccurrence> {
+        self.finish_with_unminified().0
+    }
+
+    /// Same as [`finish`](Extractor::finish), also returning the classes the
+    /// `minify` feature doesn't rewrite
+    ///
+    /// Those include every class of an ambiguous constant, whichever one the
+    /// reference ends up naming.
+    fn finish_with_unminified(mut self) -> (Vec<ClassOccurrence>, BTreeSet<String>) {
+        for pending in std::mem::take(&mut self.pending) {
+            match self.resolve(&pending).map(|v| v.map(str::to_string)) {
+                Ok(Some(value)) => self.push_classes(
+                    &value,
+                    &pending.file,
+                    pending.location,
+                    &pending.macro_name,
+                    (&pending.affix.0, &pending.affix.1),
+                    false,
+                ),
+                Ok(None) => {}
+                Err(candidates) => {
+                    let classes: Vec<String> = candidates
+                        .iter()
+                        .flat_map(|c| classnames_core_rs::tokens(&c.value))
+                        .map(|token| format!("{}{}{}", pending.affix.0, token, pending.affix.1))
+                        .collect();
+                    self.unminified.extend(classes);
+                }
+            }
+        }
+        self.occurrences.sort_by(|a, b| {
+            (&a.file, a.line, a.column, &a.class).cmp(&(&b.file, b.line, b.column, &b.class))
+        });
+        (self.occurrences, self.unminified)
+    }
+
+    /// Records the classes of `value`; `minified` tells whether the `minify`
+    /// feature rewrites them
+    fn push_classes(
+        &mut self,
+        value: &str,
+        file: &Path,
+        (line, column): (usize, usize),
+        macro_name: &str,
+        (prefix, suffix): (&str, &str),
+        minified: bool,
+    ) {
+        for token in classnames_core_rs::tokens(value) {
+            let class = format!("{}{}{}", prefix, token, suffix);
+            // Affixes are added after the literal was rewritten
+            if !minified || !prefix.is_empty() || !suffix.is_empty() {
+                self.unminified.insert(class.clone());
+            }
+            self.occurrences.push(ClassOccurrence {
+                class,
+                file: file.to_path_buf(),
+                line,
+                column,
+                macro_name: macro_name.to_string(),
+            });
+        }
+    }
+}
+
+/// Scans a single source string and returns its occurrences
+///
+/// Constants are only resolved against items in the same source; use
+/// [`Extractor`] to resolve them across files.
+pub fn extract_source(source: &str) -> syn::Result<Vec<ClassOccurrence>> {
+    let mut extractor = Extractor::new();
+    extractor.add_source(Path::new(""), source)?;
+    Ok(extractor.finish())
+}
+
+/// Returns the sorted, de-duplicated set of class names
+pub fn classes(occurrences: &[ClassOccurrence]) -> BTreeSet<String> {
+    occurrences.iter().map(|o| o.class.clone()).collect()
+}
+
+/// Last path segment of a macro invocation, e.g. `classnames` for `classnames_rs::classnames!`
+fn invoked_name(mac: &Macro) -> String {
+    mac.path
+        .segments
+        .last()
+        .map(|s| s.ident.to_string())
+        .unwrap_or_default()
+}
+
+/// Parses the arguments of `theme!`, returning the classes of every entry
+fn theme_entries(input: ParseStream) -> syn::Result<Vec<Expr>> {
+    input.parse::<Expr>()?;
+    input.parse::<Token![,]>()?;
+    if !input.peek(syn::token::Brace) {
+        input.call(syn::Path::parse_mod_style)?;
+    }
+    let content;
+    syn::braced!(content in input);
+    let entries = Punctuated::<FieldValue, Token![,]>::parse_terminated(&content)?;
+    input.parse::<Option<Token![,]>>()?;
+    Ok(entries.into_iter().map(|entry| entry.expr).collect())
+}
+
+/// Empty prefix and suffix
+fn no_affix() -> (String, String) {
+    (String::new(), String::new())
+}
+
+/// Comparison of stylesheet class selectors against classes used in Rust sources
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_extract_rs::{css::parse_css, extract_source, UsageReport};
+/// use std::path::Path;
+///
+/// let selectors = parse_css(Path::new("app.css"), ".btn {} .legacy {}");
+/// let used = extract_source(r#"fn f() { classnames!("btn", "missing"); }"#).unwrap();
+/// let report = UsageReport::new(&selectors, &used);
+/// assert_eq!(report.unused_selectors[0].class, "legacy");
+/// assert_eq!(report.unmatched_classes[0].class, "missing");
+/// assert!(!report.is_clean());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageReport {
+    /// Class selectors never referenced by a macro invocation
+    pub unused_selectors: Vec<CssClass>,
+    /// Class names used in macro invocations without a matching selector
+    pub unmatched_classes: Vec<ClassOccurrence>,
+}
+
+impl UsageReport {
+    /// Compares `selectors` against `occurrences`
+    ///
+    /// Every unused selector and every unmatched occurrence is listed, so the
+    /// same class can appear once per location.
+    pub fn new(selectors: &[CssClass], occurrences: &[ClassOccurrence]) -> Self {
+        let used = classes(occurrences);
+        let styled: BTreeSet<&str> = selectors.iter().map(|s| s.class.as_str()).collect();
+        UsageReport {
+            unused_selectors: selectors
+                .iter()
+                .filter(|s| !used.contains(&s.class))
+                .cloned()
+                .collect(),
+            unmatched_classes: occurrences
+                .iter()
+                .filter(|o| !styled.contains(o.class.as_str()))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Returns whether every selector is used and every class is styled
+    pub fn is_clean(&self) -> bool {
+        self.unused_selectors.is_empty() && self.unmatched_classes.is_empty()
+    }
+}
+
+/// 1-based line and column of a span
+fn location(span: Span) -> (usize, usize) {
+    let start = span.start();
+    (start.line, start.column + 1)
+}
+
+struct SourceVisitor<'a> {
+    extractor: &'a mut Extractor,
+    file: &'a Path,
+    /// Module being visited, from the crate root
+    module: Vec<String>,
+    /// Whether the `minify` feature rewrites literals at the current position
+    minified: bool,
+    /// First tokens file error
+    error: Option<String>,
+}
+
+impl SourceVisitor<'_> {
+    /// Tokens of the default tokens file for this source
+    fn default_tokens(&mut self) -> Tokens {
+        let path = self.extractor.tokens_path(self.file, None);
+        match self.extractor.tokens(path) {
+            Ok(tokens) => tokens.clone(),
+            Err(message) => {
+                self.error.get_or_insert(message);
+                Tokens::new()
+            }
+        }
+    }
+
+    /// Replaces `@group.name` design token references with their classes as
+    /// a string literal, or with `()` if the tokens file doesn't define them
+    ///
+    /// Without this the invocation would not parse as an expression list. An
+    /// `@` right after an identifier is a pattern binding and is kept.
+    fn resolve_token_references(
+        &mut self,
+        tokens: TokenStream,
+        file_tokens: &mut Option<Tokens>,
+    ) -> TokenStream {
+        let mut out: Vec<TokenTree> = Vec::new();
+        let mut iter = tokens.into_iter().peekable();
+        while let Some(tree) = iter.next() {
+            match tree {
+                TokenTree::Punct(punct)
+                    if punct.as_char() == '@'
+                        && !matches!(out.last(), Some(TokenTree::Ident(_)))
+                        && matches!(iter.peek(), Some(TokenTree::Ident(_))) =>
+                {
+                    let mut path = iter.next().map(|t| t.to_string()).unwrap_or_default();
+                    while matches!(iter.peek(), Some(TokenTree::Punct(dot)) if dot.as_char() == '.')
+                    {
+                        iter.next();
+                        if let Some(TokenTree::Ident(ident)) = iter.peek() {
+                            path = format!("{}.{}", path, ident);
+                            iter.next();
+                        }
+                    }
+                    let file_tokens = file_tokens.get_or_insert_with(|| self.default_tokens());
+                    out.push(match file_tokens.get(&path) {
+                        Some(classes) => {
+                            let mut literal = Literal::string(classes);
+                            literal.set_span(punct.span());
+                            TokenTree::Literal(literal)
+                        }
+                        None => {
+                            let mut unit = Group::new(Delimiter::Parenthesis, TokenStream::new());
+                            unit.set_span(punct.span());
+                            TokenTree::Group(unit)
+                        }
+                    });
+                }
+                TokenTree::Group(group) => {
+                    let mut rewritten = Group::new(
+                        group.delimiter(),
+                        self.resolve_token_references(group.stream(), file_tokens),
+                    );
+                    rewritten.set_span(group.span());
+                    out.push(TokenTree::Group(rewritten));
+                }
+                tree => out.push(tree),
+            }
+        }
+        out.into_iter().collect()
+    }
+
+    /// Records the constants a `design_tokens!` call generates in the current module
+    fn design_tokens(&mut self, tokens: TokenStream) {
+        let explicit = syn::parse2::<Option<LitStr>>(tokens)
+            .ok()
+            .flatten()
+            .map(|lit| lit.value());
+        let path = self.extractor.tokens_path(self.file, explicit.as_deref());
+        let tokens = match self.extractor.tokens(path) {
+            Ok(tokens) => tokens.clone(),
+            Err(message) => {
+                self.error.get_or_insert(message);
+                return;
+            }
+        };
+        for (path, value) in tokens {
+            let mut module = self.module.clone();
+            let mut segments: Vec<&str> = path.split('.').collect();
+            let name = segments.pop().unwrap_or_default().to_uppercase();
+            module.extend(segments.into_iter().map(str::to_string));
+            self.extractor
+                .constants
+                .entry(name)
+                .or_default()
+                .push(Constant {
+                    file: self.file.to_path_buf(),
+                    module,
+                    value,
+                });
+        }
+    }
+
+    /// Handles an invocation of one of our macros
+    ///
+    /// `affix` is the prefix and suffix applied by enclosing `prefix_classes!`-style macros.
+    fn class_macro(&mut self, name: &str, tokens: TokenStream, affix: &(String, String)) {
+        let minified = self.minified;
+        self.minified = match name {
+            "choose" | "when" | "maybe" | "theme" => minified,
+            _ => MINIFIED_MACROS.contains(&name),
+        };
+        self.class_args(name, tokens, affix);
+        self.minified = minified;
+    }
+
+    fn class_args(&mut self, name: &str, tokens: TokenStream, affix: &(String, String)) {
+        let tokens = self.resolve_token_references(tokens, &mut None);
+        // `theme!(value, Theme { light: classes, ... })` or `theme!(value, { ... })`
+        if name == "theme" {
+            match theme_entries.parse2(tokens.clone()) {
+                Ok(entries) => entries
+                    .iter()
+                    .for_each(|entry| self.class_value(entry, name, affix)),
+                Err(_) => self.scan_tokens(tokens),
+            }
+            return;
+        }
+        let Ok(args) = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(tokens.clone())
+        else {
+            // Not an expression list; still look for nested invocations
+            self.scan_tokens(tokens);
+            return;
+        };
+        let args: Vec<Expr> = args.into_iter().collect();
+
+        match name {
+            // The first argument is the condition
+            "choose" | "when" => args
+                .iter()
+                .skip(1)
+                .for_each(|arg| self.class_value(arg, name, affix)),
+            "prefix_classes" | "suffix_classes" | "affix_classes" => {
+                let literal = |expr: Option<&Expr>| match expr {
+                    Some(Expr::Lit(ExprLit {
+                        lit: Lit::Str(s), ..
+                    })) => s.value(),
+                    _ => String::new(),
+                };
+                let (prefix, suffix) = match name {
+                    "prefix_classes" => (literal(args.first()), String::new()),
+                    "suffix_classes" => (String::new(), literal(args.first())),
+                    _ => (literal(args.first()), literal(args.get(2))),
+                };
+                // Inner affixes are applied first, so they end up closest to the class
+                let affix = (affix.0.clone() + &prefix, suffix + &affix.1);
+                if let Some(input) = args.get(1) {
+                    self.class_value(input, name, &affix);
+                }
+            }
+            _ => args
+                .iter()
+                .for_each(|arg| self.class_value(arg, name, affix)),
+        }
+    }
+
+    /// Collects classes from an expression in class position
+    fn class_value(&mut self, expr: &Expr, macro_name: &str, affix: &(String, String)) {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(s), ..
+            }) => self.extractor.push_classes(
+                &s.value(),
+                self.file,
+                location(s.span()),
+                macro_name,
+                (&affix.0, &affix.1),
+                self.minified,
+            ),
+            Expr::Path(path) if path.qself.is_none() => {
+                let start = path.path.segments.first().map(|s| s.ident.span());
+                let mut segments: Vec<String> = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .collect();
+                let name = segments.pop().unwrap_or_default();
+                // `crate::`, `self::` and `super::` only say where the path starts
+                segments.retain(|s| !matches!(s.as_str(), "crate" | "self" | "super"));
+                self.extractor.pending.push(PendingConstant {
+                    qualifier: segments,
+                    name,
+                    file: self.file.to_path_buf(),
+                    module: self.module.clone(),
+                    location: start.map_or((0, 0), location),
+                    macro_name: macro_name.to_string(),
+                    affix: affix.clone(),
+                });
+            }
+            // Conditional tuples: the first element is the condition
+            Expr::Tuple(tuple) if tuple.elems.len() == 2 || tuple.elems.len() == 3 => tuple
+                .elems
+                .iter()
+                .skip(1)
+                .for_each(|elem| self.class_value(elem, macro_name, affix)),
+            Expr::If(expr_if) => {
+                self.class_block(&expr_if.then_branch, macro_name, affix);
+                if let Some((_, else_expr)) = &expr_if.else_branch {
+                    self.class_value(else_expr, macro_name, affix);
+                }
+            }
+            Expr::Match(expr_match) => expr_match
+                .arms
+                .iter()
+                .for_each(|arm| self.class_value(&arm.body, macro_name, affix)),
+            Expr::Block(expr_block) => self.class_block(&expr_block.block, macro_name, affix),
+            Expr::Array(array) => array
+                .elems
+                .iter()
+                .for_each(|elem| self.class_value(elem, macro_name, affix)),
+            Expr::Call(call) => {
+                // `Some("class")`
+                if matches!(&*call.func, Expr::Path(p) if p.path.is_ident("Some")) {
+                    call.args
+                        .iter()
+                        .for_each(|arg| self.class_value(arg, macro_name, affix));
+                }
+            }
+            Expr::Paren(paren) => self.class_value(&paren.expr, macro_name, affix),
+            Expr::Group(group) => self.class_value(&group.expr, macro_name, affix),
+            // `minify` leaves references as they are
+            Expr::Reference(reference) => {
+                let minified = std::mem::replace(&mut self.minified, false);
+                self.class_value(&reference.expr, macro_name, affix);
+                self.minified = minified;
+            }
+            Expr::Macro(expr_macro) => {
+                let name = invoked_name(&expr_macro.mac);
+                if is_known_macro(&name) {
+                    self.class_macro(&name, expr_macro.mac.tokens.clone(), affix);
+                } else {
+                    self.scan_tokens(expr_macro.mac.tokens.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn class_block(&mut self, block: &Block, macro_name: &str, affix: &(String, String)) {
+        if let Some(Stmt::Expr(tail, None)) = block.stmts.last() {
+            self.class_value(tail, macro_name, affix);
+        }
+    }
+
+    /// Looks for `name!(...)` invocations inside arbitrary macro input
+    fn scan_tokens(&mut self, tokens: TokenStream) {
+        // `minify` doesn't look inside other macros
+        let minified = std::mem::replace(&mut self.minified, false);
+        self.scan_trees(tokens);
+        self.minified = minified;
+    }
+
+    fn scan_trees(&mut self, tokens: TokenStream) {
+        let trees: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, tree) in trees.iter().enumerate() {
+            match tree {
+                TokenTree::Ident(ident) if is_known_macro(&ident.to_string()) => {
+                    if let (Some(TokenTree::Punct(bang)), Some(TokenTree::Group(group))) =
+                        (trees.get(i + 1), trees.get(i + 2))
+                    {
+                        if bang.as_char() == '!' {
+                            self.class_macro(&ident.to_string(), group.stream(), &no_affix());
+                        }
+                    }
+                }
+                TokenTree::Group(group) => {
+                    // Groups that are a known macro's arguments were handled above
+                    let is_macro_args = i >= 2
+                        && matches!(&trees[i - 1], TokenTree::Punct(p) if p.as_char() == '!')
+                        && matches!(&trees[i - 2], TokenTree::Ident(id) if is_known_macro(&id.to_string()));
+                    if !is_macro_args {
+                        self.scan_trees(group.stream());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl SourceVisitor<'_> {
+    /// Records a string constant declared in the current module
+    fn add_constant(&mut self, name: &syn::Ident, expr: &Expr) {
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) = expr
+        {
+            self.extractor
+                .constants
+                .entry(name.to_string())
+                .or_default()
+                .push(Constant {
+                    file: self.file.to_path_buf(),
+                    module: self.module.clone(),
+                    value: s.value(),
+                });
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for SourceVisitor<'_> {
+    fn visit_item_const(&mut self, item: &'ast syn::ItemConst) {
+        self.add_constant(&item.ident, &item.expr);
+        syn::visit::visit_item_const(self, item);
+    }
+
+    fn visit_item_static(&mut self, item: &'ast syn::ItemStatic) {
+        self.add_constant(&item.ident, &item.expr);
+        syn::visit::visit_item_static(self, item);
+    }
+
+    fn visit_item_mod(&mut self, item: &'ast syn::ItemMod) {
+        self.module.push(item.ident.to_string());
+        syn::visit::visit_item_mod(self, item);
+        self.module.pop();
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        let name = invoked_name(mac);
+        if name == "design_tokens" {
+            self.design_tokens(mac.tokens.clone());
+        } else if is_known_macro(&name) {
+            self.class_macro(&name, mac.tokens.clone(), &no_affix());
+        } else {
+            self.scan_tokens(mac.tokens.clone());
+        }
+    }
+}
@@ -0,0 +1,251 @@
+//! Command line interface for `classnames-extract-rs`
+//!
+//! ```text
+//! classnames-extract [--format text|json] [--locations] [--tokens FILE] [PATH]...
+//! classnames-extract unused --css <FILE>... [--format text|json] [--tokens FILE] [PATH]...
+//! ```
+//!
+//! The default mode prints every class name found in `classnames!`-family
+//! invocations below the given paths (default: `src`), one per line, or as a
+//! JSON array suitable for Tailwind's `safelist`.
+//!
+//! The `unused` mode compares those classes against stylesheets, lists dead
+//! selectors and unstyled classes with their locations, and exits with status 1
+//! when it finds any, for CI gating.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use classnames_extract_rs::{classes, css, Extractor, UsageReport};
+
+const USAGE: &str = "\
+Usage: classnames-extract [OPTIONS] [PATH]...
+       classnames-extract unused --css <FILE>... [OPTIONS] [PATH]...
+
+Extracts literal class names from classnames! macro invocations.
+
+The `unused` mode reports stylesheet selectors never referenced by any macro
+invocation and classes used in Rust without a matching selector, and exits
+with status 1 if there are any.
+
+Arguments:
+  [PATH]...            Files or directories to scan [default: src]
+
+Options:
+  --css <FILE>         Stylesheet to compare against (unused mode, repeatable)
+  --format <FORMAT>    Output format: text or json [default: text]
+  --locations          Print every occurrence as file:line:column instead of unique classes
+  --tokens <FILE>      Design tokens file for `@` references [default: tokens.toml
+                       next to each crate's Cargo.toml, or $CLASSNAMES_TOKENS]
+  -h, --help           Print help";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct Args {
+    unused: bool,
+    format: Format,
+    locations: bool,
+    css: Vec<PathBuf>,
+    tokens: Option<PathBuf>,
+    paths: Vec<PathBuf>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args {
+        unused: false,
+        format: Format::Text,
+        locations: false,
+        css: Vec::new(),
+        tokens: None,
+        paths: Vec::new(),
+    };
+    let mut first = true;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "unused" if first => parsed.unused = true,
+            "--format" => {
+                parsed.format = match args.next().as_deref() {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some(other) => return Err(format!("unknown format `{}`", other)),
+                    None => return Err("--format requires a value".to_string()),
+                }
+            }
+            "--css" => match args.next() {
+                Some(path) => parsed.css.push(PathBuf::from(path)),
+                None => return Err("--css requires a file".to_string()),
+            },
+            "--tokens" => match args.next() {
+                Some(path) => parsed.tokens = Some(PathBuf::from(path)),
+                None => return Err("--tokens requires a file".to_string()),
+            },
+            "--locations" => parsed.locations = true,
+            "-h" | "--help" => return Err(String::new()),
+            flag if flag.starts_with('-') => return Err(format!("unknown option `{}`", flag)),
+            path => parsed.paths.push(PathBuf::from(path)),
+        }
+        first = false;
+    }
+    if parsed.paths.is_empty() {
+        parsed.paths.push(PathBuf::from("src"));
+    }
+    if parsed.unused && parsed.css.is_empty() {
+        return Err("unused mode requires at least one --css file".to_string());
+    }
+    if !parsed.unused && !parsed.css.is_empty() {
+        return Err("--css is only valid in unused mode".to_string());
+    }
+    Ok(parsed)
+}
+
+/// Output of the default mode
+fn extract_output(args: &Args, extractor: Extractor) -> String {
+    let occurrences = extractor.finish();
+    match (args.format, args.locations) {
+        (Format::Text, false) => classes(&occurrences)
+            .iter()
+            .map(|class| format!("{}\n", class))
+            .collect(),
+        (Format::Text, true) => occurrences.iter().map(|o| format!("{}\n", o)).collect(),
+        (Format::Json, false) => {
+            let classes: Vec<_> = classes(&occurrences).into_iter().collect();
+            serde_json::to_string_pretty(&classes).expect("valid JSON") + "\n"
+        }
+        (Format::Json, true) => {
+            let entries: Vec<_> = occurrences
+                .iter()
+                .map(|o| {
+                    serde_json::json!({
+                        "class": o.class,
+                        "file": o.file.display().to_string(),
+                        "line": o.line,
+                        "column": o.column,
+                        "macro": o.macro_name,
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&entries).expect("valid JSON") + "\n"
+        }
+    }
+}
+
+/// Output of the unused mode
+fn report_output(args: &Args, report: &UsageReport) -> String {
+    match args.format {
+        Format::Text => {
+            let mut output = String::new();
+            for selector in &report.unused_selectors {
+                output += &format!(
+                    "{}:{}:{}: unused selector .{}\n",
+                    selector.file.display(),
+                    selector.line,
+                    selector.column,
+                    selector.class
+                );
+            }
+            for occurrence in &report.unmatched_classes {
+                output += &format!(
+                    "{}:{}:{}: no selector for `{}` ({}!)\n",
+                    occurrence.file.display(),
+                    occurrence.line,
+                    occurrence.column,
+                    occurrence.class,
+                    occurrence.macro_name
+                );
+            }
+            output
+        }
+        Format::Json => {
+            let unused: Vec<_> = report
+                .unused_selectors
+                .iter()
+                .map(|s| {
+                    serde_json::json!({
+                        "class": s.class,
+                        "file": s.file.display().to_string(),
+                        "line": s.line,
+                        "column": s.column,
+                    })
+                })
+                .collect();
+            let unmatched: Vec<_> = report
+                .unmatched_classes
+                .iter()
+                .map(|o| {
+                    serde_json::json!({
+                        "class": o.class,
+                        "file": o.file.display().to_string(),
+                        "line": o.line,
+                        "column": o.column,
+                        "macro": o.macro_name,
+                    })
+                })
+                .collect();
+            let value = serde_json::json!({
+                "unused_selectors": unused,
+                "unmatched_classes": unmatched,
+            });
+            serde_json::to_string_pretty(&value).expect("valid JSON") + "\n"
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            if message.is_empty() {
+                println!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut extractor = Extractor::new();
+    if let Some(tokens) = &args.tokens {
+        extractor.set_tokens_file(tokens);
+    }
+    for path in &args.paths {
+        if let Err(err) = extractor.add_path(path) {
+            eprintln!("error: {}", err);
+            return ExitCode::from(2);
+        }
+    }
+
+    for ambiguous in extractor.ambiguous_constants() {
+        eprintln!("warning: {}", ambiguous);
+    }
+
+    let (output, status) = if args.unused {
+        let mut selectors = Vec::new();
+        for path in &args.css {
+            match css::read_css(path) {
+                Ok(mut found) => selectors.append(&mut found),
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return ExitCode::from(2);
+                }
+            }
+        }
+        let report = UsageReport::new(&selectors, &extractor.finish());
+        let status = if report.is_clean() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+        (report_output(&args, &report), status)
+    } else {
+        (extract_output(&args, extractor), ExitCode::SUCCESS)
+    };
+
+    // A closed pipe (e.g. `| head`) is not worth reporting
+    let _ = std::io::stdout().write_all(output.as_bytes());
+    status
+}
@@ -0,0 +1,135 @@
+//! Class name manifest for the `minify` feature of `classnames-rs`
+//!
+//! A crate that minifies its class names calls [`write_manifest`] from its
+//! build script. The manifest maps every class found in the crate's sources to
+//! a short name, and the macros rewrite literal class names with it, so the
+//! names depend only on the set of classes the sources use, not on the order
+//! the compiler expands the macros in.
+//!
+//! Classes the macros don't rewrite, because they come from a constant, a
+//! `design_tokens!` accessor or a helper used outside a class list, keep their
+//! own name, and no other class is given that name as its short name.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use crate::{classes, Error, Extractor};
+
+/// File name of the manifest in `OUT_DIR`, as read by `classnames-rs`
+pub const MANIFEST_FILE: &str = "classnames-manifest.json";
+
+/// Returns the name at `index` in the sequence of short names: every letter,
+/// then every letter followed by a letter or digit, and so on
+fn sequential_name(mut index: usize) -> String {
+    const DIGITS: &[u8; 36] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+    let mut len = 1;
+    let mut count = 26;
+    while index >= count {
+        index -= count;
+        len += 1;
+        count *= 36;
+    }
+
+    let mut name = String::with_capacity(len);
+    name.push(DIGITS[index % 26] as char);
+    index /= 26;
+    for _ in 1..len {
+        name.push(DIGITS[index % 36] as char);
+        index /= 36;
+    }
+    name
+}
+
+/// Takes the next name of the sequence from `index` that isn't a known class,
+/// or returns `class` itself if that name wouldn't be shorter
+fn next_name(index: &mut usize, class: &str, known: &BTreeSet<String>) -> String {
+    loop {
+        let name = sequential_name(*index);
+        if name.len() >= class.len() {
+            return class.to_string();
+        }
+        *index += 1;
+        if !known.contains(&name) {
+            return name;
+        }
+    }
+}
+
+/// Maps every class found by `extractor` to its short name
+///
+/// Classes are named in sorted order with the names `a`, `b`, ..., `z`, `aa`,
+/// `ab`, ..., skipping names that are classes themselves. A class keeps its own
+/// name when no shorter one is left, and when the macros don't rewrite it.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_extract_rs::{minify::short_names, Extractor};
+/// use std::path::Path;
+///
+/// let source = r#"
+///     const WIDE: &str = "wide";
+///     fn f(on: bool) -> String {
+///         classnames!("btn", "btn-primary", (on, "b"), WIDE, "wide")
+///     }
+/// "#;
+/// let mut extractor = Extractor::new();
+/// extractor.add_source(Path::new("a.rs"), source).unwrap();
+/// let names = short_names(extractor);
+/// assert_eq!(names["b"], "b");
+/// assert_eq!(names["btn"], "a");
+/// assert_eq!(names["btn-primary"], "c");
+/// assert_eq!(names["wide"], "wide");
+/// ```
+pub fn short_names(extractor: Extractor) -> BTreeMap<String, String> {
+    let (occurrences, unminified) = extractor.finish_with_unminified();
+    let mut known = classes(&occurrences);
+    known.extend(unminified.iter().cloned());
+
+    let mut index = 0;
+    known
+        .iter()
+        .map(|class| {
+            let short = if unminified.contains(class) {
+                class.clone()
+            } else {
+                next_name(&mut index, class, &known)
+            };
+            (class.clone(), short)
+        })
+        .collect()
+}
+
+/// Scans `sources` and writes their [`short_names`] to `out_dir`
+///
+/// Call it from the build script of the crate that minifies, with every file or
+/// directory whose macros should be rewritten. A literal class missing from the
+/// manifest is a compile error. Cargo reruns a build script whenever a file of
+/// the package changes, unless it prints `cargo:rerun-if-changed`; in that case
+/// list the sources there too.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // In `fn main` of build.rs
+/// println!("cargo:rustc-env=CLASSNAMES_MINIFY=my_app");
+/// let out_dir = std::env::var_os("OUT_DIR").unwrap();
+/// classnames_extract_rs::minify::write_manifest(&["src"], out_dir.as_ref()).unwrap();
+/// ```
+pub fn write_manifest<P: AsRef<Path>>(
+    sources: &[P],
+    out_dir: &Path,
+) -> Result<BTreeMap<String, String>, Error> {
+    let mut extractor = Extractor::new();
+    for source in sources {
+        extractor.add_path(source.as_ref())?;
+    }
+    let names = short_names(extractor);
+
+    let path = out_dir.join(MANIFEST_FILE);
+    let content = serde_json::to_string_pretty(&names).expect("a map of strings serializes");
+    fs::write(&path, content).map_err(|e| Error::Io(path, e))?;
+    Ok(names)
+}
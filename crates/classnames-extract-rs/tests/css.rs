@@ -0,0 +1,139 @@
+use std::path::Path;
+
+use classnames_extract_rs::{css::parse_css, extract_source, UsageReport};
+
+fn css_classes(source: &str) -> Vec<String> {
+    parse_css(Path::new("app.css"), source)
+        .into_iter()
+        .map(|c| c.class)
+        .collect()
+}
+
+#[test]
+fn test_selectors() {
+    let css = r#"
+        .btn, .btn-primary:hover { color: red; }
+        div.card > .title + .subtitle ~ p { margin: 0; }
+        .a.b:not(.c) { }
+        #id, p, * { }
+    "#;
+    assert_eq!(
+        css_classes(css),
+        [
+            "btn",
+            "btn-primary",
+            "card",
+            "title",
+            "subtitle",
+            "a",
+            "b",
+            "c"
+        ]
+    );
+}
+
+#[test]
+fn test_declarations_are_skipped() {
+    let css = r#"
+        .box {
+            width: 1.5em;
+            background: url("img/bg.png") no-repeat;
+            content: ".not-a-class";
+            font: 12px/1.5 sans-serif
+        }
+    "#;
+    assert_eq!(css_classes(css), ["box"]);
+}
+
+#[test]
+fn test_comments_strings_and_attributes() {
+    let css = r#"
+        /* .commented { } */
+        a[href$=".pdf"] .icon { }
+        .x::after { content: "}"; }
+        .y { }
+    "#;
+    assert_eq!(css_classes(css), ["icon", "x", "y"]);
+}
+
+#[test]
+fn test_at_rules() {
+    let css = r#"
+        @import url("base.css");
+        @media (min-width: 768px) {
+            .md-only { display: block; }
+            @supports (display: grid) { .grid { display: grid; } }
+        }
+        @keyframes spin { from { opacity: 0.5; } 50.5% { opacity: 1; } }
+        @font-face { font-family: X; src: url(x.woff); }
+        .after { }
+    "#;
+    assert_eq!(css_classes(css), ["md-only", "grid", "after"]);
+}
+
+#[test]
+fn test_nesting() {
+    let css = r#"
+        .card {
+            padding: 0;
+            & .title { }
+            .body { color: red; }
+            &:hover { }
+        }
+    "#;
+    assert_eq!(css_classes(css), ["card", "title", "body"]);
+}
+
+#[test]
+fn test_escapes() {
+    let css = r#"
+        .md\:w-1\/2 { }
+        .hover\:bg-blue-500:hover { }
+        .\31 0 { }
+        .w-\[10px\] { }
+    "#;
+    assert_eq!(
+        css_classes(css),
+        ["md:w-1/2", "hover:bg-blue-500", "10", "w-[10px]"]
+    );
+}
+
+#[test]
+fn test_locations() {
+    let found = parse_css(Path::new("app.css"), "p {}\n  .btn { }\n");
+    assert_eq!(found.len(), 1);
+    assert_eq!((found[0].line, found[0].column), (2, 3));
+    assert_eq!(found[0].file, Path::new("app.css"));
+}
+
+#[test]
+fn test_usage_report() {
+    let selectors = parse_css(
+        Path::new("app.css"),
+        ".btn {}\n.active {}\n.legacy {}\n.md\\:w-1\\/2 {}",
+    );
+    let used = extract_source(
+        r#"
+        fn f(on: bool) -> String {
+            classnames!("btn md:w-1/2", (on, "active"), "unstyled")
+        }
+        "#,
+    )
+    .unwrap();
+    let report = UsageReport::new(&selectors, &used);
+
+    assert_eq!(report.unused_selectors.len(), 1);
+    assert_eq!(report.unused_selectors[0].class, "legacy");
+    assert_eq!(report.unused_selectors[0].line, 3);
+
+    assert_eq!(report.unmatched_classes.len(), 1);
+    assert_eq!(report.unmatched_classes[0].class, "unstyled");
+    assert_eq!(report.unmatched_classes[0].line, 3);
+    assert!(!report.is_clean());
+
+    let styled = parse_css(
+        Path::new("app.css"),
+        ".btn {} .md\\:w-1\\/2 {} .active {} .unstyled {}",
+    );
+    assert!(UsageReport::new(&styled, &used).is_clean());
+}
@@ -0,0 +1,451 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use classnames_extract_rs::{classes, css::parse_css, extract_source, Extractor, UsageReport};
+
+fn extract(source: &str) -> Vec<String> {
+    classes(&extract_source(source).unwrap())
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_literals_and_conditions() {
+    let source = r#"
+        fn class(active: bool, dark: bool) -> String {
+            classnames!(
+                "btn  btn-primary",
+                (active, "active"),
+                (dark, "dark", "light"),
+                if active { "on" } else { "off" }
+            )
+        }
+    "#;
+    assert_eq!(
+        extract(source),
+        ["active", "btn", "btn-primary", "dark", "light", "off", "on"]
+    );
+}
+
+#[test]
+fn test_conditions_are_not_classes() {
+    let source = r#"
+        fn class(theme: &str) -> String {
+            classnames!((theme == "dark-mode", "dark"), choose!(theme == "x", "a", "b"))
+        }
+    "#;
+    assert_eq!(extract(source), ["a", "b", "dark"]);
+}
+
+#[test]
+fn test_helper_macros() {
+    let source = r#"
+        fn class(active: bool, size: Option<&str>) -> String {
+            let a = choose!(active, "primary", "secondary");
+            let b = when!(active, "loading");
+            let c = maybe!(Some("optional"));
+            let d = pretty_classname!("  messy \n class ");
+            classnames!(when!(active, "nested"), maybe!(size))
+        }
+    "#;
+    assert_eq!(
+        extract(source),
+        [
+            "class",
+            "loading",
+            "messy",
+            "nested",
+            "optional",
+            "primary",
+            "secondary"
+        ]
+    );
+}
+
+#[test]
+fn test_const_macros() {
+    let source = r#"
+        const BASE: &str = "card";
+        const A: &str = classnames_concat!("flex", BASE, "  p-4 ");
+        const B: &str = prefix_classes!("app1-", classnames_concat!("btn", "btn-primary"));
+        const C: &str = suffix_classes!("--v2", "x");
+        const D: &str = affix_classes!("p-", "y", "-s");
+    "#;
+    assert_eq!(
+        extract(source),
+        [
+            "app1-btn",
+            "app1-btn-primary",
+            "card",
+            "flex",
+            "p-4",
+            "p-y-s",
+            "x--v2"
+        ]
+    );
+}
+
+#[test]
+fn test_constants_are_resolved() {
+    let source = r#"
+        const BUTTON: &str = "btn rounded";
+        static LINK: &str = "link";
+        const UNUSED: &str = "never-referenced";
+        fn class() -> String {
+            classnames!(BUTTON, LINK, UNKNOWN)
+        }
+    "#;
+    assert_eq!(extract(source), ["btn", "link", "rounded"]);
+}
+
+#[test]
+fn test_nested_in_framework_macros() {
+    let source = r#"
+        fn view(active: bool) {
+            view! {
+                <div class=classnames!("container", (active, "is-active"))>
+                    <span class=move || choose!(active, "yes", "no")></span>
+                </div>
+            };
+            html! { <p class={classnames_rs::classnames!("para")}></p> };
+        }
+    "#;
+    assert_eq!(
+        extract(source),
+        ["container", "is-active", "no", "para", "yes"]
+    );
+}
+
+#[test]
+fn test_reactive_classnames() {
+    let source = r#"
+        fn view(active: ReadSignal<bool>) {
+            view! {
+                <div class=reactive_classnames!("menu", (active, "menu-open"), when!(active, "shadow"))></div>
+            };
+        }
+    "#;
+    assert_eq!(extract(source), ["menu", "menu-open", "shadow"]);
+}
+
+#[test]
+fn test_tokens_are_not_classes() {
+    let source = r#"
+        fn attrs(open: bool) -> (String, String) {
+            let described_by = tokens!("tooltip-1", (open, "panel-2"));
+            let rel = tokens!(rel: "noopener", (open, "noreferrer"));
+            let nested = tokens!(classnames!("btn"), "part-label");
+            (described_by, rel)
+        }
+    "#;
+    assert_eq!(extract(source), ["btn"]);
+
+    let selectors = parse_css(Path::new("app.css"), ".btn {}");
+    let report = UsageReport::new(&selectors, &extract_source(source).unwrap());
+    assert!(report.is_clean(), "{:?}", report);
+}
+
+#[test]
+fn test_small_classnames() {
+    let source = r#"
+        fn class(active: bool) -> SmallClasses {
+            small_classnames!("chip", (active, "chip-active", "chip-idle"))
+        }
+    "#;
+    assert_eq!(extract(source), ["chip", "chip-active", "chip-idle"]);
+}
+
+#[test]
+fn test_cow_classnames() {
+    let source = r#"
+        fn class(primary: bool) -> Cow<'static, str> {
+            cow_classnames!("btn", (primary, "btn-primary", "btn-secondary"))
+        }
+    "#;
+    assert_eq!(extract(source), ["btn", "btn-primary", "btn-secondary"]);
+}
+
+#[test]
+fn test_theme_macro() {
+    let source = r#"
+        fn class(theme: Theme, raised: bool) -> String {
+            classnames!(
+                "card",
+                theme!(theme, Theme {
+                    light: "bg-white",
+                    dark: ["bg-gray-900", (raised, "shadow-lg")],
+                    high_contrast: @theme.contrast,
+                })
+            )
+        }
+    "#;
+    assert_eq!(
+        extract(source),
+        ["bg-gray-900", "bg-white", "card", "shadow-lg"]
+    );
+
+    // Without an enum path, the crate's own `Theme`
+    let source = r#"
+        fn class(theme: Theme) -> String {
+            classnames!(theme!(theme, { light: "bg-white", dark: ["bg-black"], high_contrast: "x" }))
+        }
+    "#;
+    assert_eq!(extract(source), ["bg-black", "bg-white", "x"]);
+}
+
+#[test]
+fn test_match_and_blocks() {
+    let source = r#"
+        fn class(v: u8) -> String {
+            classnames!(
+                match v { 0 => "zero", _ => "many" },
+                { Some("from-block") },
+                &"referenced"
+            )
+        }
+    "#;
+    assert_eq!(
+        extract(source),
+        ["from-block", "many", "referenced", "zero"]
+    );
+}
+
+#[test]
+fn test_design_token_references_are_skipped() {
+    let source = r#"
+        fn class(primary: bool, n: u8) -> String {
+            classnames!(
+                "btn",
+                @button.base,
+                (primary, @button.primary, "btn-secondary"),
+                [@button.size.lg, "shadow"],
+                match n { m @ 0..=2 => "few", _ => @button.many }
+            )
+        }
+    "#;
+    assert_eq!(extract(source), ["btn", "btn-secondary", "few", "shadow"]);
+}
+
+/// Creates a crate in a temporary directory with the given files
+fn fixture(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "classnames-extract-{}-{}",
+        name,
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    for (path, content) in [("Cargo.toml", "[package]\nname = \"app\"\n")]
+        .iter()
+        .chain(files)
+    {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+const TOKENS: &str = r#"
+surface = "bg-white"
+
+[button]
+primary = "btn  btn-primary"
+ghost = ["btn", "btn-ghost"]
+
+[button.size]
+lg = "px-6"
+"#;
+
+#[test]
+fn test_design_token_references_are_resolved() {
+    let dir = fixture(
+        "references",
+        &[
+            ("tokens.toml", TOKENS),
+            (
+                "src/lib.rs",
+                r#"
+                fn class(ghost: bool) -> String {
+                    classnames!(@surface, (ghost, @button.ghost, @button.primary), [@button.size.lg], @button.missing)
+                }
+                "#,
+            ),
+        ],
+    );
+    let mut extractor = Extractor::new();
+    extractor.add_path(&dir.join("src")).unwrap();
+    let found = extractor.finish();
+    assert_eq!(
+        classes(&found).into_iter().collect::<Vec<_>>(),
+        ["bg-white", "btn", "btn-ghost", "btn-primary", "px-6"]
+    );
+    assert_eq!((found[0].line, found[0].column), (3, 33));
+
+    let selectors = parse_css(Path::new("app.css"), ".btn-ghost {} .px-6 {}");
+    let report = UsageReport::new(&selectors, &found);
+    assert!(report.unused_selectors.is_empty());
+}
+
+#[test]
+fn test_design_token_constants_are_resolved() {
+    let dir = fixture(
+        "constants",
+        &[
+            ("tokens.toml", TOKENS),
+            ("design/brand.toml", "[logo]\nmark = \"logo-mark\""),
+            (
+                "src/lib.rs",
+                r#"
+                mod tokens {
+                    classnames_rs::design_tokens!();
+                }
+                mod brand {
+                    classnames_rs::design_tokens!("design/brand.toml");
+                }
+                fn class() -> String {
+                    classnames!(tokens::button::PRIMARY, tokens::button::size::LG, tokens::SURFACE)
+                }
+                "#,
+            ),
+            (
+                "src/header.rs",
+                "fn class() -> String { classnames!(crate::brand::logo::MARK) }",
+            ),
+        ],
+    );
+    let mut extractor = Extractor::new();
+    extractor.add_path(&dir.join("src")).unwrap();
+    assert!(extractor.ambiguous_constants().is_empty());
+    let found = extractor.finish();
+    assert_eq!(
+        classes(&found).into_iter().collect::<Vec<_>>(),
+        ["bg-white", "btn", "btn-primary", "logo-mark", "px-6"]
+    );
+}
+
+#[test]
+fn test_invalid_tokens_file() {
+    let dir = fixture(
+        "invalid",
+        &[
+            ("tokens.toml", "button = 1"),
+            ("src/lib.rs", "fn f() { classnames!(@button); }"),
+        ],
+    );
+    let err = Extractor::new().add_path(&dir.join("src")).unwrap_err();
+    assert!(
+        err.to_string().contains("design token `button` must be"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_locations() {
+    let source = "fn f() {\n    classnames!(\"btn\",\n        \"active\");\n}\n";
+    let found = extract_source(source).unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(
+        (found[0].class.as_str(), found[0].line, found[0].column),
+        ("btn", 2, 17)
+    );
+    assert_eq!(
+        (found[1].class.as_str(), found[1].line, found[1].column),
+        ("active", 3, 9)
+    );
+    assert_eq!(found[0].macro_name, "classnames");
+}
+
+#[test]
+fn test_constants_across_files() {
+    let mut extractor = Extractor::new();
+    extractor
+        .add_source(Path::new("src/a.rs"), "fn f() { classnames!(SHARED); }")
+        .unwrap();
+    extractor
+        .add_source(
+            Path::new("src/b.rs"),
+            r#"pub const SHARED: &str = "shared";"#,
+        )
+        .unwrap();
+    let found = extractor.finish();
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].class, "shared");
+    assert_eq!(found[0].file, Path::new("src/a.rs"));
+}
+
+#[test]
+fn test_constants_are_resolved_by_module() {
+    let mut extractor = Extractor::new();
+    extractor
+        .add_source(
+            Path::new("src/admin/theme.rs"),
+            r#"pub const PRIMARY: &str = "admin-primary";"#,
+        )
+        .unwrap();
+    extractor
+        .add_source(
+            Path::new("src/lib.rs"),
+            r#"
+            mod site {
+                pub const PRIMARY: &str = "site-primary";
+                fn f() { classnames!(PRIMARY); }
+            }
+            fn g() { classnames!(crate::admin::theme::PRIMARY, site::PRIMARY); }
+            "#,
+        )
+        .unwrap();
+    assert!(extractor.ambiguous_constants().is_empty());
+    let found = extractor.finish();
+    let classes: Vec<_> = found.iter().map(|o| (o.line, o.class.as_str())).collect();
+    assert_eq!(
+        classes,
+        [
+            (4, "site-primary"),
+            (6, "admin-primary"),
+            (6, "site-primary")
+        ]
+    );
+}
+
+#[test]
+fn test_ambiguous_constants_are_reported() {
+    let mut extractor = Extractor::new();
+    extractor
+        .add_source(
+            Path::new("src/a.rs"),
+            r#"pub const PRIMARY: &str = "a-primary";"#,
+        )
+        .unwrap();
+    extractor
+        .add_source(
+            Path::new("src/b.rs"),
+            r#"pub const PRIMARY: &str = "b-primary";"#,
+        )
+        .unwrap();
+    extractor
+        .add_source(
+            Path::new("src/c.rs"),
+            r#"fn f() { classnames!("c", PRIMARY, b::PRIMARY); }"#,
+        )
+        .unwrap();
+
+    let ambiguous = extractor.ambiguous_constants();
+    assert_eq!(ambiguous.len(), 1);
+    assert_eq!(ambiguous[0].name, "PRIMARY");
+    assert_eq!(ambiguous[0].file, Path::new("src/c.rs"));
+    assert_eq!(ambiguous[0].candidates, ["a::PRIMARY", "b::PRIMARY"]);
+    assert_eq!(
+        ambiguous[0].to_string(),
+        "src/c.rs:1:27: `PRIMARY` could be any of a::PRIMARY, b::PRIMARY; qualify it with its module"
+    );
+
+    let found = extractor.finish();
+    let classes: Vec<_> = found.iter().map(|o| o.class.as_str()).collect();
+    assert_eq!(classes, ["c", "b-primary"]);
+}
+
+#[test]
+fn test_invalid_source() {
+    assert!(extract_source("fn {").is_err());
+}
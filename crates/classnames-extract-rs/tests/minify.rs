@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use classnames_extract_rs::minify::{short_names, write_manifest, MANIFEST_FILE};
+use classnames_extract_rs::Extractor;
+
+fn names(sources: &[(&str, &str)]) -> BTreeMap<String, String> {
+    let mut extractor = Extractor::new();
+    for (file, source) in sources {
+        extractor.add_source(Path::new(file), source).unwrap();
+    }
+    short_names(extractor)
+}
+
+const BUTTON: &str = r#"
+    fn button(primary: bool) -> String {
+        classnames!("btn", (primary, "btn-primary", "btn-secondary"))
+    }
+"#;
+
+const CARD: &str = r#"
+    fn card(open: bool) -> String {
+        classnames!("card  shadow", when!(open, "card-open"), choose!(open, "btn", "x"))
+    }
+"#;
+
+#[test]
+fn test_names_depend_only_on_the_classes() {
+    let forward = names(&[("a.rs", BUTTON), ("b.rs", CARD)]);
+    let backward = names(&[("b.rs", CARD), ("a.rs", BUTTON)]);
+    assert_eq!(forward, backward);
+    assert_eq!(
+        forward.into_iter().collect::<Vec<_>>(),
+        [
+            ("btn", "a"),
+            ("btn-primary", "b"),
+            ("btn-secondary", "c"),
+            ("card", "d"),
+            ("card-open", "e"),
+            ("shadow", "f"),
+            ("x", "x"),
+        ]
+        .map(|(class, short)| (class.to_string(), short.to_string()))
+    );
+}
+
+#[test]
+fn test_short_classes_keep_their_name() {
+    let names = names(&[("a.rs", r#"fn f() { classnames!("a", "b", "grid", "c"); }"#)]);
+    assert_eq!(names["a"], "a");
+    assert_eq!(names["b"], "b");
+    assert_eq!(names["c"], "c");
+    assert_eq!(names["grid"], "d");
+}
+
+#[test]
+fn test_unrewritten_classes_keep_their_name() {
+    let source = r#"
+        const SHORT: &str = "a";
+        mod theme { pub const ACCENT: &str = "accent"; }
+        mod admin { pub const ACCENT: &str = "b"; }
+        fn f(on: bool) {
+            classnames!("btn", "accent", SHORT, theme::ACCENT, "card", "d");
+            classnames!(ACCENT);
+            classnames!(&"ref", prefix_classes!("md:", "grid"));
+            let label = choose!(on, "open", "closed");
+            classnames!(format!("{}", when!(on, "e")));
+        }
+    "#;
+    let names = names(&[("src/lib.rs", source)]);
+    for class in ["a", "accent", "ref", "md:grid", "open", "closed", "e"] {
+        assert_eq!(names[class], class);
+    }
+    // `b` may come from the ambiguous `ACCENT`, so no class is renamed to it
+    assert_eq!(names["b"], "b");
+    assert_eq!(names["btn"], "c");
+    assert_eq!(names["card"], "f");
+    assert_eq!(names["d"], "d");
+    let mut shorts: Vec<_> = names.values().collect();
+    shorts.sort();
+    shorts.dedup();
+    assert_eq!(shorts.len(), names.len());
+}
+
+#[test]
+fn test_write_manifest() {
+    let dir = std::env::temp_dir().join(format!(
+        "classnames-extract-manifest-{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src")).unwrap();
+    fs::write(dir.join("src/button.rs"), BUTTON).unwrap();
+    fs::write(dir.join("src/card.rs"), CARD).unwrap();
+
+    let names = write_manifest(&[dir.join("src")], &dir).unwrap();
+    let written: BTreeMap<String, String> =
+        serde_json::from_str(&fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap()).unwrap();
+    assert_eq!(written, names);
+    assert_eq!(names["btn"], "a");
+
+    assert!(write_manifest(&[dir.join("missing")], &dir).is_err());
+}
@@ -0,0 +1,361 @@
+//! Design tokens loaded from a TOML file (`design-tokens` feature)
+//!
+//! A tokens file maps dotted paths to class lists:
+//!
+//! ```toml
+//! [button]
+//! primary = "bg-blue-600 text-white"
+//! ghost = ["bg-transparent", "text-blue-600"]
+//! ```
+//!
+//! `design_tokens!` turns it into modules of constants, and the `classnames!`
+//! family replaces `@button.primary` with the normalized value as a string
+//! literal. Every expansion that reads the file also `include_bytes!`s it, so
+//! cargo rebuilds the crate whenever the file changes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use proc_macro2::{Group, Ident, Literal, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::LitStr;
+
+use crate::suggest::suggest;
+
+/// File `@` references resolve against, relative to `CARGO_MANIFEST_DIR`
+const DEFAULT_FILE: &str = "tokens.toml";
+
+/// Environment variable overriding `DEFAULT_FILE`, e.g. set by a build script
+/// with `cargo:rustc-env=CLASSNAMES_TOKENS=design/tokens.toml`
+///
+/// Cargo doesn't track environment variables read by proc macros, so changing
+/// it doesn't re-expand crates that were already built.
+const FILE_VAR: &str = "CLASSNAMES_TOKENS";
+
+/// A parsed tokens file: leaves hold normalized class lists
+enum Node {
+    Value(String),
+    Group(Vec<(String, Node)>),
+}
+
+struct TokenFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    root: Vec<(String, Node)>,
+}
+
+/// The last file read, reused while its modification time is unchanged
+static CACHE: Mutex<Option<TokenFile>> = Mutex::new(None);
+
+fn manifest_dir() -> Result<PathBuf, String> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            "design tokens are resolved against CARGO_MANIFEST_DIR, which is not set".to_string()
+        })
+}
+
+/// Path of the tokens file `@` references resolve against
+fn default_path() -> Result<PathBuf, String> {
+    let file = std::env::var(FILE_VAR).unwrap_or_else(|_| DEFAULT_FILE.to_string());
+    Ok(manifest_dir()?.join(file))
+}
+
+fn parse_table(table: toml::Table, prefix: &str) -> Result<Vec<(String, Node)>, String> {
+    table
+        .into_iter()
+        .map(|(key, value)| {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            if syn::parse_str::<Ident>(&key).is_err() {
+                return Err(format!(
+                    "design token key `{}` is not a valid identifier",
+                    path
+                ));
+            }
+            let node = match value {
+                toml::Value::String(classes) => {
+                    Node::Value(classnames_core_rs::normalize(&classes))
+                }
+                toml::Value::Array(items) => {
+                    let mut classes = String::new();
+                    for item in items {
+                        let item = item.as_str().ok_or_else(|| {
+                            format!("design token `{}` must only list strings", path)
+                        })?;
+                        classnames_core_rs::push_normalized(&mut classes, item);
+                    }
+                    Node::Value(classes)
+                }
+                toml::Value::Table(table) => Node::Group(parse_table(table, &path)?),
+                _ => {
+                    return Err(format!(
+                        "design token `{}` must be a string, an array of strings or a table",
+                        path
+                    ))
+                }
+            };
+            Ok((key, node))
+        })
+        .collect()
+}
+
+/// Runs `f` on the parsed file at `path`, reading it only if it changed
+fn with_file<T>(path: &Path, f: impl FnOnce(&[(String, Node)]) -> T) -> Result<T, String> {
+    let modified = fs::metadata(path)
+        .map_err(|e| format!("failed to read design tokens {}: {}", path.display(), e))?
+        .modified()
+        .ok();
+
+    let mut guard = CACHE.lock().map_err(|e| e.to_string())?;
+    if guard
+        .as_ref()
+        .is_none_or(|file| file.path != path || file.modified != modified || modified.is_none())
+    {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read design tokens {}: {}", path.display(), e))?;
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e| format!("invalid design tokens {}: {}", path.display(), e))?;
+        let root = parse_table(table, "").map_err(|e| format!("{} in {}", e, path.display()))?;
+        *guard = Some(TokenFile {
+            path: path.to_path_buf(),
+            modified,
+            root,
+        });
+    }
+    Ok(f(&guard.as_ref().expect("tokens were just loaded").root))
+}
+
+/// Dotted paths of every value in `nodes`
+fn value_paths(nodes: &[(String, Node)], prefix: &str, out: &mut Vec<String>) {
+    for (key, node) in nodes {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match node {
+            Node::Value(_) => out.push(path),
+            Node::Group(children) => value_paths(children, &path, out),
+        }
+    }
+}
+
+fn lookup(root: &[(String, Node)], path: &str) -> Result<String, String> {
+    let mut nodes = root;
+    let mut segments = path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        match nodes
+            .iter()
+            .find(|(key, _)| key == segment)
+            .map(|(_, node)| node)
+        {
+            Some(Node::Value(classes)) if segments.peek().is_none() => return Ok(classes.clone()),
+            Some(Node::Group(children)) if segments.peek().is_some() => nodes = children,
+            Some(Node::Group(_)) => {
+                return Err(format!(
+                    "design token `{}` is a group; reference one of its values",
+                    path
+                ))
+            }
+            _ => break,
+        }
+    }
+
+    let mut candidates = Vec::new();
+    value_paths(root, "", &mut candidates);
+    let candidates: Vec<&str> = candidates.iter().map(String::as_str).collect();
+    Err(match suggest(path, &candidates) {
+        Some(suggestion) => format!(
+            "unknown design token `{}`; did you mean `{}`?",
+            path, suggestion
+        ),
+        None => format!("unknown design token `{}`", path),
+    })
+}
+
+/// `const _` item that makes cargo rebuild the crate when `path` changes
+fn track(path: &Path) -> TokenStream {
+    let path = path.to_string_lossy();
+    quote! {
+        const _: &[u8] = ::core::include_bytes!(#path);
+    }
+}
+
+/// Replaces every `@group.name` reference in `tokens`, including inside nested
+/// groups and macro calls, with its class list as a string literal
+///
+/// Returns the rewritten tokens and, if any reference was found, an item to
+/// expand alongside them so that the crate is rebuilt when the file changes.
+/// An `@` right after an identifier is a pattern binding (`n @ 1..=9`) and is
+/// left alone.
+pub(crate) fn resolve_references(tokens: TokenStream) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut path = None;
+    let tokens = rewrite(tokens, &mut path)?;
+    Ok((tokens, path.as_deref().map(track).unwrap_or_default()))
+}
+
+fn rewrite(tokens: TokenStream, file: &mut Option<PathBuf>) -> syn::Result<TokenStream> {
+    let mut out = Vec::new();
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(tree) = iter.next() {
+        match tree {
+            TokenTree::Punct(punct)
+                if punct.as_char() == '@'
+                    && !matches!(out.last(), Some(TokenTree::Ident(_)))
+                    && matches!(iter.peek(), Some(TokenTree::Ident(_))) =>
+            {
+                let mut segments = Vec::new();
+                let mut reference = vec![TokenTree::Punct(punct.clone())];
+                loop {
+                    match iter.next() {
+                        Some(TokenTree::Ident(ident)) => {
+                            segments.push(ident.to_string());
+                            reference.push(TokenTree::Ident(ident));
+                        }
+                        other => {
+                            reference.extend(other);
+                            return Err(syn::Error::new_spanned(
+                                TokenStream::from_iter(reference),
+                                "expected a design token name after `.`",
+                            ));
+                        }
+                    }
+                    match iter.peek() {
+                        Some(TokenTree::Punct(dot)) if dot.as_char() == '.' => {
+                            reference.extend(iter.next());
+                        }
+                        _ => break,
+                    }
+                }
+                let token_path = segments.join(".");
+
+                let reference = TokenStream::from_iter(reference);
+                let error = |message: String| syn::Error::new_spanned(&reference, message);
+                let path = match file {
+                    Some(path) => path.clone(),
+                    None => default_path().map_err(error)?,
+                };
+                let classes = with_file(&path, |root| lookup(root, &token_path))
+                    .and_then(|result| result)
+                    .map_err(error)?;
+                *file = Some(path);
+
+                let mut literal = Literal::string(&classes);
+                literal.set_span(punct.span());
+                out.push(TokenTree::Literal(literal));
+            }
+            TokenTree::Group(group) => {
+                let mut rewritten = Group::new(group.delimiter(), rewrite(group.stream(), file)?);
+                rewritten.set_span(group.span());
+                out.push(TokenTree::Group(rewritten));
+            }
+            tree => out.push(tree),
+        }
+    }
+    Ok(out.into_iter().collect())
+}
+
+/// Expands `design_tokens!` for the file at `file`, relative to
+/// `CARGO_MANIFEST_DIR`, or the default tokens file
+pub(crate) fn expand_design_tokens(file: Option<LitStr>) -> syn::Result<TokenStream> {
+    let span = file.as_ref().map_or_else(Span::call_site, LitStr::span);
+    let error = |message: String| syn::Error::new(span, message);
+    let path = match &file {
+        Some(file) => manifest_dir().map_err(error)?.join(file.value()),
+        None => default_path().map_err(error)?,
+    };
+    let items = with_file(&path, |root| accessors(root, ""))
+        .and_then(|result| result)
+        .map_err(error)?;
+    let track = track(&path);
+    Ok(quote! {
+        #track
+        #items
+    })
+}
+
+/// Modules and constants for `nodes`, whose dotted paths start with `prefix`
+///
+/// Fails if two values of a table generate the same constant, such as
+/// `primary` and `PRIMARY`.
+fn accessors(nodes: &[(String, Node)], prefix: &str) -> Result<TokenStream, String> {
+    let path = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+    let mut consts: Vec<(String, &str)> = Vec::new();
+    let mut items = Vec::with_capacity(nodes.len());
+    for (key, node) in nodes {
+        items.push(match node {
+            Node::Value(classes) => {
+                let constant = key.to_uppercase();
+                if let Some((_, other)) = consts.iter().find(|(name, _)| *name == constant) {
+                    return Err(format!(
+                        "design token keys `{}` and `{}` both generate the constant `{}`",
+                        path(other),
+                        path(key),
+                        constant
+                    ));
+                }
+                let name = format_ident!("{}", constant);
+                consts.push((constant, key));
+                let doc = format!("`{}`", classes);
+                quote! {
+                    #[doc = #doc]
+                    pub const #name: &str = #classes;
+                }
+            }
+            Node::Group(children) => {
+                let name = format_ident!("{}", key);
+                let items = accessors(children, &path(key))?;
+                quote! {
+                    pub mod #name {
+                        #items
+                    }
+                }
+            }
+        });
+    }
+    Ok(quote! { #(#items)* })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> Vec<(String, Node)> {
+        parse_table(source.parse().unwrap(), "").unwrap()
+    }
+
+    #[test]
+    fn test_accessors() {
+        let nodes = parse("surface = \"bg-white\"\n[button]\nprimary = \"a\"\nghost = \"b\"");
+        let items = accessors(&nodes, "").unwrap().to_string();
+        assert!(items.contains("pub const SURFACE"), "{}", items);
+        assert!(items.contains("pub mod button"), "{}", items);
+        assert!(items.contains("pub const PRIMARY"), "{}", items);
+    }
+
+    #[test]
+    fn test_keys_generating_the_same_constant() {
+        let nodes = parse("[button]\nprimary = \"a\"\nPRIMARY = \"b\"");
+        assert_eq!(
+            accessors(&nodes, "").unwrap_err(),
+            "design token keys `button.PRIMARY` and `button.primary` both generate the constant `PRIMARY`"
+        );
+
+        // A group and a value only share a name in different namespaces
+        let nodes = parse("primary = \"a\"\n[PRIMARY]\nbase = \"b\"");
+        assert!(accessors(&nodes, "").is_ok());
+    }
+}
@@ -0,0 +1,176 @@
+//! Keyword validation for `tokens!` attributes
+//!
+//! Attributes whose value is a set of keywords defined by the HTML standard can
+//! be named in `tokens!(rel: ...)`; string literals in token positions are then
+//! checked against the keyword list at compile time.
+
+use syn::punctuated::Punctuated;
+use syn::{Block, Expr, ExprLit, Lit, Stmt, Token};
+
+use crate::suggest::suggest;
+
+/// Link types allowed in `rel` (HTML link types plus widely used extensions)
+const REL: &[&str] = &[
+    "alternate",
+    "apple-touch-icon",
+    "apple-touch-icon-precomposed",
+    "apple-touch-startup-image",
+    "author",
+    "bookmark",
+    "canonical",
+    "compression-dictionary",
+    "dns-prefetch",
+    "expect",
+    "external",
+    "help",
+    "icon",
+    "license",
+    "manifest",
+    "mask-icon",
+    "me",
+    "modulepreload",
+    "next",
+    "nofollow",
+    "noopener",
+    "noreferrer",
+    "opener",
+    "pingback",
+    "preconnect",
+    "prefetch",
+    "preload",
+    "prerender",
+    "prev",
+    "privacy-policy",
+    "search",
+    "shortcut",
+    "sponsored",
+    "stylesheet",
+    "tag",
+    "terms-of-service",
+    "ugc",
+];
+
+/// Restrictions lifted by `sandbox` on `<iframe>`
+const SANDBOX: &[&str] = &[
+    "allow-downloads",
+    "allow-forms",
+    "allow-modals",
+    "allow-orientation-lock",
+    "allow-pointer-lock",
+    "allow-popups",
+    "allow-popups-to-escape-sandbox",
+    "allow-presentation",
+    "allow-same-origin",
+    "allow-scripts",
+    "allow-storage-access-by-user-activation",
+    "allow-top-navigation",
+    "allow-top-navigation-by-user-activation",
+    "allow-top-navigation-to-custom-protocols",
+];
+
+/// Attributes with a known keyword list
+const ATTRIBUTES: &[(&str, &[&str])] = &[("rel", REL), ("sandbox", SANDBOX)];
+
+/// Returns the keyword list of `attribute`
+pub(crate) fn keywords(attribute: &str) -> Option<&'static [&'static str]> {
+    ATTRIBUTES
+        .iter()
+        .find(|(name, _)| *name == attribute)
+        .map(|(_, keywords)| *keywords)
+}
+
+/// Names of the attributes with a known keyword list, for error messages
+pub(crate) fn attribute_names() -> String {
+    ATTRIBUTES
+        .iter()
+        .map(|(name, _)| format!("`{}`", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Checks the string literals in token positions of a `tokens!` argument
+///
+/// Conditions are skipped; literals in tuple values, `if`/block results, `match`
+/// arms and `when!`/`choose!` values are checked. Keywords are ASCII case-insensitive.
+pub(crate) fn check_literals(expr: &Expr, attribute: &str, keywords: &[&str]) -> syn::Result<()> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => {
+            for token in classnames_core_rs::tokens(&s.value()) {
+                if keywords.iter().any(|k| k.eq_ignore_ascii_case(token)) {
+                    continue;
+                }
+                let lowercase = token.to_ascii_lowercase();
+                let message = match suggest(&lowercase, keywords) {
+                    Some(suggestion) => format!(
+                        "unknown `{}` keyword `{}`; did you mean `{}`?",
+                        attribute, token, suggestion
+                    ),
+                    None => format!("unknown `{}` keyword `{}`", attribute, token),
+                };
+                return Err(syn::Error::new(s.span(), message));
+            }
+            Ok(())
+        }
+        Expr::Tuple(tuple) if tuple.elems.len() == 2 || tuple.elems.len() == 3 => tuple
+            .elems
+            .iter()
+            .skip(1)
+            .try_for_each(|elem| check_literals(elem, attribute, keywords)),
+        Expr::If(expr_if) => {
+            check_block(&expr_if.then_branch, attribute, keywords)?;
+            match &expr_if.else_branch {
+                Some((_, else_expr)) => check_literals(else_expr, attribute, keywords),
+                None => Ok(()),
+            }
+        }
+        Expr::Block(expr_block) => check_block(&expr_block.block, attribute, keywords),
+        Expr::Match(expr_match) => expr_match
+            .arms
+            .iter()
+            .try_for_each(|arm| check_literals(&arm.body, attribute, keywords)),
+        Expr::Array(array) => array
+            .elems
+            .iter()
+            .try_for_each(|elem| check_literals(elem, attribute, keywords)),
+        Expr::Call(call) if matches!(&*call.func, Expr::Path(path) if path.path.is_ident("Some")) => {
+            call.args
+                .iter()
+                .try_for_each(|arg| check_literals(arg, attribute, keywords))
+        }
+        Expr::Paren(paren) => check_literals(&paren.expr, attribute, keywords),
+        Expr::Group(group) => check_literals(&group.expr, attribute, keywords),
+        Expr::Macro(expr_macro) => {
+            let is_conditional = expr_macro
+                .mac
+                .path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "when" || s.ident == "choose");
+            if !is_conditional {
+                return Ok(());
+            }
+            match expr_macro
+                .mac
+                .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+            {
+                Ok(args) => args
+                    .iter()
+                    .skip(1)
+                    .try_for_each(|arg| check_literals(arg, attribute, keywords)),
+                // Malformed invocations are reported by the macro itself
+                Err(_) => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Checks the tail expression of a block
+fn check_block(block: &Block, attribute: &str, keywords: &[&str]) -> syn::Result<()> {
+    match block.stmts.last() {
+        Some(Stmt::Expr(tail, None)) => check_literals(tail, attribute, keywords),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,1768 @@
+/// Conditional selection helper macro for simplifying conditional class name logic.
+///
+/// Accepts three parameters:
+/// - condition: A conditional expression
+/// - true_value: Class name returned when condition is true
+/// - false_value: Class name returned when condition is false
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{classnames, choose};
+///
+/// let is_active = true;
+/// let result = classnames!(
+///     "btn",
+///     choose!(is_active, "active", "inactive")
+/// );
+/// assert_eq!(result, "btn active");
+///
+/// // Can be combined with classnames! macro
+/// let is_dark = false;
+/// let size = "large";
+/// let result = classnames!(
+///     "theme",
+///     choose!(is_dark, "dark", "light"),
+///     size
+/// );
+/// assert_eq!(result, "theme light large");
+/// ```
+use proc_macro::TokenStream;
+use proc_macro_crate::FoundCrate;
+use quote::quote;
+use syn::{
+    parse::{discouraged::Speculative, Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    Arm, Expr, ExprArray, ExprBlock, ExprCall, ExprGroup, ExprIf, ExprMacro, ExprMatch, ExprParen,
+    ExprTuple, Ident, Token,
+};
+
+mod css_properties;
+mod keywords;
+mod suggest;
+
+#[cfg(feature = "design-tokens")]
+mod design_tokens;
+
+#[cfg(feature = "minify")]
+mod minify;
+
+#[cfg(feature = "minify")]
+use minify::rewrite_literals;
+
+/// Path to `classnames-rs` in the crate being compiled
+///
+/// Expansions call into `classnames_rs::__private`, so this follows the name
+/// the calling crate gave the dependency in its `Cargo.toml`, e.g.
+/// `cn = { package = "classnames-rs", ... }`. `classnames-rs` itself, its
+/// doctests, and crates that don't list it get `::classnames_rs`.
+fn crate_path() -> proc_macro2::TokenStream {
+    match proc_macro_crate::crate_name("classnames-rs") {
+        Ok(FoundCrate::Name(name)) => {
+            let name = Ident::new(&name, proc_macro2::Span::call_site());
+            quote! { ::#name }
+        }
+        Ok(FoundCrate::Itself) | Err(_) => quote! { ::classnames_rs },
+    }
+}
+
+/// Literal class names are only rewritten when the `minify` feature is enabled
+#[cfg(not(feature = "minify"))]
+fn rewrite_literals(exprs: Vec<Expr>) -> syn::Result<Vec<Expr>> {
+    Ok(exprs)
+}
+
+#[cfg(feature = "design-tokens")]
+use design_tokens::resolve_references;
+
+/// `@token.path` references are only resolved when the `design-tokens` feature
+/// is enabled
+#[cfg(not(feature = "design-tokens"))]
+fn resolve_references(
+    tokens: proc_macro2::TokenStream,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    Ok((tokens, proc_macro2::TokenStream::new()))
+}
+
+struct ClassNamesInput {
+    exprs: Vec<Expr>,
+}
+
+impl Parse for ClassNamesInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let exprs = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+        Ok(ClassNamesInput {
+            exprs: exprs.into_iter().collect(),
+        })
+    }
+}
+
+/// A procedural macro for dynamically building CSS class names.
+///
+/// # Features
+/// - Support for string literals
+/// - Support for conditional class names
+/// - Support for Option types (Use `maybe!` macro)
+/// - Support for ternary expressions
+/// - Support for block expressions
+/// - Support for `match` expressions
+/// - Automatic whitespace normalization
+///
+/// The result is always a newly allocated `String`. Calls made only of literals
+/// and literal tuples pick it from a precomputed table, but still copy it into
+/// that `String`, so they allocate once. Only `cow_classnames!` borrows the
+/// table entry and doesn't allocate.
+///
+/// # Examples
+///
+/// ### Basic usage:
+/// ```rust
+/// use classnames_rs::classnames;
+///
+/// let result = classnames!("btn", "btn-primary");
+/// assert_eq!(result, "btn btn-primary");
+/// ```
+///
+/// ### Conditional class names:
+/// ```rust
+/// use classnames_rs::classnames;
+///
+/// let is_active = true;
+/// let result = classnames!(
+///     "btn",
+///     (is_active, "active")
+/// );
+/// assert_eq!(result, "btn active");
+/// ```
+///
+/// ### Option types:
+/// ```rust
+/// use classnames_rs::{classnames, maybe};
+///
+/// let optional_class: Option<&str> = Some("highlight");
+/// let result = classnames!("base", maybe!(optional_class));
+/// assert_eq!(result, "base highlight");
+/// ```
+///
+/// ### Ternary expressions:
+/// ```rust
+/// use classnames_rs::classnames;
+///
+/// let is_dark = true;
+/// let result = classnames!(
+///     "theme",
+///     if is_dark { "dark" } else { "light" }
+/// );
+/// assert_eq!(result, "theme dark");
+/// ```
+///
+/// ### Triple tuple conditions:
+/// ```rust
+/// use classnames_rs::classnames;
+///
+/// let count = 5;
+/// let result = classnames!(
+///     "list",
+///     (count > 0, "has-items", "empty")
+/// );
+/// assert_eq!(result, "list has-items");
+/// ```
+///
+/// ### Several classes per condition:
+///
+/// A `[...]` list in a tuple takes any `classnames!` arguments, including
+/// nested conditions and helper macros.
+/// ```rust
+/// use classnames_rs::{classnames, maybe};
+///
+/// let is_open = true;
+/// let accent: Option<&str> = Some("accent-blue");
+/// let result = classnames!(
+///     "menu",
+///     (is_open, ["menu-open", "shadow-lg", maybe!(accent)]),
+///     (is_open, ["visible"], ["hidden", "sr-only"])
+/// );
+/// assert_eq!(result, "menu menu-open shadow-lg accent-blue visible");
+/// ```
+///
+/// ### Match expressions:
+///
+/// Each arm may yield a literal, an `Option`, a `[...]` list of further
+/// arguments, or nothing (`()`, `{}` or `None`); arms don't need to agree on a
+/// type. Literal arms are normalized at compile time.
+/// ```rust
+/// use classnames_rs::{classnames, maybe};
+///
+/// enum Size { Small, Medium, Large }
+///
+/// let size = Size::Large;
+/// let icon: Option<&str> = Some("with-icon");
+/// let result = classnames!(
+///     "btn",
+///     match size {
+///         Size::Small => "btn-sm",
+///         Size::Medium => (),
+///         Size::Large => ["btn-lg", maybe!(icon)],
+///     }
+/// );
+/// assert_eq!(result, "btn btn-lg with-icon");
+/// ```
+#[proc_macro]
+pub fn classnames(input: TokenStream) -> TokenStream {
+    class_macro(input, expand)
+}
+
+/// Parses and expands a `classnames!`-style argument list with `expand`
+///
+/// Resolves `@token.path` references first, so they may appear anywhere in the
+/// arguments.
+fn class_macro(
+    input: TokenStream,
+    expand: impl FnOnce(Vec<Expr>) -> proc_macro2::TokenStream,
+) -> TokenStream {
+    let (input, track) = match resolve_references(input.into()) {
+        Ok(resolved) => resolved,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let input = match syn::parse2::<ClassNamesInput>(input) {
+        Ok(input) => input,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let exprs = match rewrite_literals(input.exprs) {
+        Ok(exprs) => exprs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let expanded = expand(exprs);
+    if track.is_empty() {
+        expanded.into()
+    } else {
+        quote! {
+            {
+                #track
+                #expanded
+            }
+        }
+        .into()
+    }
+}
+
+/// Builds the `String` of a `classnames!`-style argument list
+fn expand(exprs: Vec<Expr>) -> proc_macro2::TokenStream {
+    expand_into(exprs, quote! { ::std::string::String::new() })
+}
+
+/// Builds a `classnames!`-style argument list into the buffer `buffer` creates
+fn expand_into(exprs: Vec<Expr>, buffer: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let exprs = merge_literals(exprs);
+    if let Some(tokens) = lookup_table(&exprs, &buffer) {
+        return tokens;
+    }
+    let tokens = exprs.into_iter().map(parse_expr);
+
+    quote! {
+        {
+            let mut classes = #buffer;
+            #(#tokens)*
+            classes
+        }
+    }
+}
+
+/// Most conditions `lookup_table` precomputes every combination of
+const MAX_TABLE_CONDITIONS: usize = 6;
+
+/// An argument whose possible values are all known at expansion time
+enum StaticArg {
+    Literal(String),
+    /// `(cond, "a")`, `(cond, "a", "b")`, `when!(cond, "a")` or
+    /// `choose!(cond, "a", "b")`; the `false` value of a pair is empty
+    Conditional(Box<Expr>, String, String),
+}
+
+/// Returns the value of a string literal argument
+fn literal_value(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Group(ExprGroup { expr, .. }) => literal_value(expr),
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(s),
+            ..
+        }) => Some(s.value()),
+        _ => None,
+    }
+}
+
+fn static_arg(expr: &Expr) -> Option<StaticArg> {
+    match expr {
+        Expr::Group(ExprGroup { expr, .. }) => static_arg(expr),
+        Expr::Tuple(ExprTuple { elems, .. }) if elems.len() == 2 || elems.len() == 3 => {
+            let then_value = literal_value(&elems[1])?;
+            let else_value = match elems.get(2) {
+                Some(value) => literal_value(value)?,
+                None => String::new(),
+            };
+            Some(StaticArg::Conditional(
+                Box::new(elems[0].clone()),
+                then_value,
+                else_value,
+            ))
+        }
+        Expr::Macro(ExprMacro { mac, .. }) => {
+            let name = mac.path.segments.last()?.ident.to_string();
+            let args: Vec<_> = mac
+                .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                .ok()?
+                .into_iter()
+                .collect();
+            let else_value = match (name.as_str(), args.as_slice()) {
+                ("when", [_, _]) => String::new(),
+                ("choose", [_, _, else_value]) => literal_value(else_value)?,
+                _ => return None,
+            };
+            let then_value = literal_value(&args[1])?;
+            // The helper still expands, and its output tells which value it took
+            let selected = classnames_core_rs::normalize(&then_value);
+            Some(StaticArg::Conditional(
+                Box::new(syn::parse_quote! { (#expr == #selected) }),
+                then_value,
+                else_value,
+            ))
+        }
+        _ => literal_value(expr).map(StaticArg::Literal),
+    }
+}
+
+/// Expands an argument list made of literals and 1 to `MAX_TABLE_CONDITIONS`
+/// literal tuples into a static table of every possible output, indexed by
+/// the bitmask of the conditions
+fn lookup_table(
+    exprs: &[Expr],
+    buffer: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    let krate = crate_path();
+    let args = exprs.iter().map(static_arg).collect::<Option<Vec<_>>>()?;
+    let conditions: Vec<_> = args
+        .iter()
+        .filter_map(|arg| match arg {
+            StaticArg::Conditional(cond, ..) => Some(cond),
+            StaticArg::Literal(_) => None,
+        })
+        .collect();
+    if conditions.is_empty() || conditions.len() > MAX_TABLE_CONDITIONS {
+        return None;
+    }
+
+    let len = 1usize << conditions.len();
+    let mut entries = Vec::with_capacity(len);
+    for mask in 0..len {
+        let mut bit = 0;
+        let mut joined = String::new();
+        for arg in &args {
+            let value = match arg {
+                StaticArg::Literal(value) => value,
+                StaticArg::Conditional(_, then_value, else_value) => {
+                    let value = if mask >> bit & 1 == 1 {
+                        then_value
+                    } else {
+                        else_value
+                    };
+                    bit += 1;
+                    value
+                }
+            };
+            joined.push(' ');
+            joined.push_str(value);
+        }
+        entries.push(classnames_core_rs::normalize(&joined));
+    }
+
+    let bits = conditions.iter().enumerate().map(|(i, cond)| {
+        quote! { (::core::primitive::usize::from(#cond) << #i) }
+    });
+    Some(quote! {
+        {
+            static __CLASSNAMES_TABLE: [&::core::primitive::str; #len] = [#(#entries),*];
+            let index = 0 #(| #bits)*;
+            let mut classes = #buffer;
+            #krate::__private::push_literal(&mut classes, __CLASSNAMES_TABLE[index]);
+            classes
+        }
+    })
+}
+
+/// Joins runs of adjacent string literal arguments into a single literal
+fn merge_literals(exprs: Vec<Expr>) -> Vec<Expr> {
+    let mut merged: Vec<Expr> = Vec::with_capacity(exprs.len());
+    for expr in exprs {
+        if let (
+            Some(Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(previous),
+                ..
+            })),
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(next),
+                ..
+            }),
+        ) = (merged.last_mut(), &expr)
+        {
+            let value = format!("{} {}", previous.value(), next.value());
+            *previous = syn::LitStr::new(&value, previous.span());
+            continue;
+        }
+        merged.push(expr);
+    }
+    merged
+}
+
+/// Same as `classnames!`, returning a `Cow<'static, str>` that borrows when a
+/// single string literal makes up the result
+///
+/// Adjacent literals are joined at compile time, and `(cond, "a")`,
+/// `(cond, "a", "b")` and `if` chains whose branches are all literals select a
+/// literal without copying it. Up to six literal tuples next to literals are
+/// looked up in a table of every combination, so the whole result borrows. It
+/// also borrows for `when!(cond, "a")` and `choose!(cond, "a", "b")`, which
+/// count as literal tuples. It allocates as soon as a dynamic value (a
+/// variable, `maybe!`, a helper with a non-literal value, ...) contributes.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use classnames_rs::cow_classnames;
+///
+/// let is_primary = true;
+/// let is_disabled = false;
+///
+/// let class = cow_classnames!("btn", "btn-lg", (is_disabled, "disabled"));
+/// assert!(matches!(class, Cow::Borrowed("btn btn-lg")));
+///
+/// let class = cow_classnames!((is_primary, "btn-primary", "btn-secondary"));
+/// assert!(matches!(class, Cow::Borrowed("btn-primary")));
+///
+/// let class = cow_classnames!(if is_disabled { "muted" } else { "bold" });
+/// assert!(matches!(class, Cow::Borrowed("bold")));
+///
+/// let class = cow_classnames!("btn", (is_primary, "btn-primary"));
+/// assert!(matches!(class, Cow::Borrowed("btn btn-primary")));
+///
+/// let size = String::from("btn-lg");
+/// let class = cow_classnames!("btn", size);
+/// assert!(matches!(class, Cow::Owned(_)));
+/// assert_eq!(class, "btn btn-lg");
+/// ```
+#[proc_macro]
+pub fn cow_classnames(input: TokenStream) -> TokenStream {
+    class_macro(input, |exprs| {
+        expand_into(
+            exprs,
+            quote! { ::std::borrow::Cow::<'static, str>::Borrowed("") },
+        )
+    })
+}
+
+/// Same as `classnames!`, writing into an inline buffer that only spills to the
+/// heap past 64 bytes
+///
+/// Returns `classnames_rs::SmallClasses`, which dereferences to `str`. Requires
+/// the `smallstr` feature of `classnames-rs`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use classnames_rs::small_classnames;
+///
+/// let is_active = true;
+/// let classes = small_classnames!("btn", (is_active, "active"));
+/// assert_eq!(&*classes, "btn active");
+/// assert!(!classes.spilled());
+/// ```
+#[proc_macro]
+pub fn small_classnames(input: TokenStream) -> TokenStream {
+    class_macro(input, |exprs| {
+        let krate = crate_path();
+        expand_into(exprs, quote! { #krate::SmallClasses::new() })
+    })
+}
+
+/// Generates a constant for every class list of a design tokens file
+///
+/// Reads `tokens.toml` from `CARGO_MANIFEST_DIR`, or the file the
+/// `CLASSNAMES_TOKENS` environment variable names, or the path given as a
+/// string literal, both relative to `CARGO_MANIFEST_DIR`. Tables become
+/// modules, values become `&str` constants named in uppercase, and class lists
+/// are normalized. Two keys of a table that only differ in case, such as
+/// `primary` and `PRIMARY`, would generate the same constant and are a compile
+/// error. The crate is rebuilt whenever the file changes. Requires the
+/// `design-tokens` feature of `classnames-rs`.
+///
+/// Cargo doesn't track `CLASSNAMES_TOKENS` for proc macros: after pointing it
+/// at another file, run `cargo clean -p <your crate>` or touch a source file so
+/// that the macros are expanded again.
+///
+/// # Examples
+///
+/// With a `tokens.toml` of
+///
+/// ```toml
+/// [button]
+/// primary = "bg-blue-600  text-white"
+/// ghost = ["bg-transparent", "text-blue-600"]
+/// ```
+///
+/// ```rust,ignore
+/// mod tokens {
+///     classnames_rs::design_tokens!();
+/// }
+///
+/// assert_eq!(tokens::button::PRIMARY, "bg-blue-600 text-white");
+/// assert_eq!(tokens::button::GHOST, "bg-transparent text-blue-600");
+/// ```
+///
+/// The same file backs `@button.primary` references in `classnames!`.
+#[cfg(feature = "design-tokens")]
+#[proc_macro]
+pub fn design_tokens(input: TokenStream) -> TokenStream {
+    let file = parse_macro_input!(input as Option<syn::LitStr>);
+    match design_tokens::expand_design_tokens(file) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct TokensInput {
+    attribute: Option<Ident>,
+    exprs: Vec<Expr>,
+}
+
+impl Parse for TokensInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attribute = if input.peek(Ident) && input.peek2(Token![:]) && !input.peek2(Token![::]) {
+            let attribute = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Some(attribute)
+        } else {
+            None
+        };
+        let ClassNamesInput { exprs } = input.parse()?;
+        Ok(TokensInput { attribute, exprs })
+    }
+}
+
+/// Builds a space-separated token list for any attribute, such as `rel`,
+/// `aria-describedby`, `part` or `sandbox`
+///
+/// Takes the same arguments as `classnames!`. Prefixing the arguments with
+/// `rel:` or `sandbox:` checks every literal keyword at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{tokens, when};
+///
+/// let external = true;
+/// let rel = tokens!(rel: "noopener", (external, "noreferrer external"), when!(false, "nofollow"));
+/// assert_eq!(rel, "noopener noreferrer external");
+///
+/// let has_hint = true;
+/// let describedby = tokens!("email-label", (has_hint, "email-hint"));
+/// assert_eq!(describedby, "email-label email-hint");
+///
+/// let trusted = false;
+/// let sandbox = tokens!(sandbox: "allow-scripts", (trusted, "allow-same-origin"));
+/// assert_eq!(sandbox, "allow-scripts");
+/// ```
+///
+/// Unknown keywords are rejected:
+///
+/// ```rust,compile_fail
+/// use classnames_rs::tokens;
+///
+/// let rel = tokens!(rel: "noopner");
+/// ```
+#[proc_macro]
+pub fn tokens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as TokensInput);
+
+    if let Some(attribute) = &input.attribute {
+        let name = attribute.to_string();
+        let Some(keywords) = keywords::keywords(&name) else {
+            return syn::Error::new(
+                attribute.span(),
+                format!(
+                    "no keyword list for `{}`; known attributes are {} \
+                     (omit the attribute name to skip validation)",
+                    name,
+                    keywords::attribute_names()
+                ),
+            )
+            .to_compile_error()
+            .into();
+        };
+        for expr in &input.exprs {
+            if let Err(err) = keywords::check_literals(expr, &name, keywords) {
+                return err.to_compile_error().into();
+            }
+        }
+    }
+
+    expand(input.exprs).into()
+}
+
+fn parse_expr(expr: Expr) -> proc_macro2::TokenStream {
+    let krate = crate_path();
+
+    match expr {
+        // Invisible groups: arguments forwarded by `macro_rules!` as `$x:expr`
+        Expr::Group(ExprGroup { expr, .. }) => parse_expr(*expr),
+        // Regular Path (constants or variable references)
+        Expr::Path(path) => {
+            quote! {
+                {
+                    use #krate::__private::PushClass as _;
+                    let class_str = &#path;
+                    class_str.push_class(&mut classes);
+                }
+            }
+        }
+        Expr::Reference(expr_ref) => {
+            quote! {
+                {
+                    let class_str = #expr_ref;
+                    #krate::__private::push_normalized(&mut classes, &class_str.to_string());
+                }
+            }
+        }
+        // String literals: "text"
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(s),
+            ..
+        }) => {
+            // Literals are normalized once, at expansion time
+            let value = classnames_core_rs::normalize(&s.value());
+            quote! {
+                #krate::__private::push_literal(&mut classes, #value);
+            }
+        }
+        // Tuple conditions: (cond, "class") or (cond, [...])
+        Expr::Tuple(ExprTuple { elems, .. }) if elems.len() == 2 => {
+            let mut elems = elems.into_iter();
+            let cond = elems.next().unwrap();
+            let class = push_branch(elems.next().unwrap());
+            quote! {
+                if #cond {
+                    #class
+                }
+            }
+        }
+        // Triple tuple conditions: (cond, true_value, false_value), where either
+        // value may be a list
+        Expr::Tuple(ExprTuple { elems, .. }) if elems.len() == 3 => {
+            let mut elems = elems.into_iter();
+            let cond = elems.next().unwrap();
+            let true_val = push_branch(elems.next().unwrap());
+            let false_val = push_branch(elems.next().unwrap());
+            quote! {
+                if #cond {
+                    #true_val
+                } else {
+                    #false_val
+                }
+            }
+        }
+        // `if` chains whose branches are all literals push them directly
+        Expr::If(expr_if) if is_literal_if(&expr_if) => literal_if(expr_if),
+        // Ternary expressions: cond ? a : b
+        Expr::If(ExprIf {
+            cond,
+            then_branch,
+            else_branch,
+            ..
+        }) => {
+            if let Some((_, else_expr)) = else_branch {
+                quote! {
+                    {
+                        let value = if #cond {
+                            #then_branch
+                        } else {
+                            #else_expr
+                        };
+                        #krate::__private::push_normalized(&mut classes, &value.to_string());
+                    }
+                }
+            } else {
+                // Handle cases without else branch
+                quote! {
+                    if #cond {
+                        #krate::__private::push_normalized(&mut classes, &#then_branch.to_string());
+                    }
+                }
+            }
+        }
+        // Block expressions: if x { ... }
+        Expr::Block(ExprBlock { block, .. }) => {
+            quote! {
+                {
+                    let result = #block;
+                    if let Some(class) = result {
+                        #krate::__private::push_normalized(&mut classes, &class.to_string());
+                    }
+                }
+            }
+        }
+        // Match expressions: each arm pushes its own classes
+        Expr::Match(ExprMatch { expr, arms, .. }) => {
+            let arms = arms.into_iter().map(|arm| {
+                let Arm {
+                    attrs,
+                    pat,
+                    guard,
+                    body,
+                    ..
+                } = arm;
+                let guard = guard.map(|(if_token, cond)| quote! { #if_token #cond });
+                let body = parse_arm(*body);
+                quote! {
+                    #(#attrs)*
+                    #pat #guard => { #body }
+                }
+            });
+            quote! {
+                match #expr {
+                    #(#arms)*
+                }
+            }
+        }
+        // Other expressions (variables, function calls, etc.)
+        _ => push_display(&expr),
+    }
+}
+
+/// Pushes the `Display` output of `expr`
+fn push_display(expr: &Expr) -> proc_macro2::TokenStream {
+    let krate = crate_path();
+    quote! {
+        #krate::__private::push_normalized(&mut classes, &(#expr).to_string());
+    }
+}
+
+/// Returns the string literal a block consists of, if any
+fn literal_tail(block: &syn::Block) -> Option<&syn::LitStr> {
+    match block.stmts.as_slice() {
+        [syn::Stmt::Expr(
+            Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }),
+            None,
+        )] => Some(s),
+        _ => None,
+    }
+}
+
+/// Returns `true` if every branch of an `if` chain is a string literal
+fn is_literal_if(expr_if: &ExprIf) -> bool {
+    literal_tail(&expr_if.then_branch).is_some()
+        && match &expr_if.else_branch {
+            None => true,
+            Some((_, else_expr)) => match &**else_expr {
+                Expr::Block(ExprBlock { block, .. }) => literal_tail(block).is_some(),
+                Expr::If(else_if) => is_literal_if(else_if),
+                _ => false,
+            },
+        }
+}
+
+/// Expands an `if` chain accepted by `is_literal_if`
+fn literal_if(expr_if: ExprIf) -> proc_macro2::TokenStream {
+    let cond = &expr_if.cond;
+    let literal = |block: &syn::Block| {
+        parse_expr(Expr::Lit(syn::ExprLit {
+            attrs: Vec::new(),
+            lit: syn::Lit::Str(literal_tail(block).unwrap().clone()),
+        }))
+    };
+    let then_branch = literal(&expr_if.then_branch);
+    let else_branch = match expr_if.else_branch.map(|(_, else_expr)| *else_expr) {
+        None => quote! {},
+        Some(Expr::Block(ExprBlock { block, .. })) => {
+            let tokens = literal(&block);
+            quote! { else { #tokens } }
+        }
+        Some(Expr::If(else_if)) => {
+            let tokens = literal_if(else_if);
+            quote! { else #tokens }
+        }
+        Some(_) => unreachable!("checked by is_literal_if"),
+    };
+    quote! {
+        if #cond {
+            #then_branch
+        } #else_branch
+    }
+}
+
+/// Expands a tuple value: a `[...]` list takes nested arguments, anything else
+/// is a single class value
+fn push_branch(value: Expr) -> proc_macro2::TokenStream {
+    match value {
+        Expr::Array(ExprArray { elems, .. }) => {
+            let tokens = elems.into_iter().map(parse_expr);
+            quote! { #(#tokens)* }
+        }
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(_),
+            ..
+        }) => parse_expr(value),
+        Expr::Group(ExprGroup { expr, .. }) => push_branch(*expr),
+        _ => push_display(&value),
+    }
+}
+
+/// Returns the normalized literal of every value, if they all are string literals
+fn static_branches<'a>(values: impl IntoIterator<Item = &'a Expr>) -> Option<Vec<String>> {
+    values
+        .into_iter()
+        .map(|value| literal_value(value).map(|value| classnames_core_rs::normalize(&value)))
+        .collect()
+}
+
+/// Builds the `String` of a `choose!` value; a `[...]` list takes nested arguments
+fn branch_string(value: &Expr) -> proc_macro2::TokenStream {
+    match value {
+        Expr::Array(ExprArray { elems, .. }) => expand(elems.iter().cloned().collect()),
+        _ => {
+            let krate = crate_path();
+            quote! {
+                #krate::__private::normalize(&#value.to_string())
+            }
+        }
+    }
+}
+
+/// Expands the body of a `match` arm inside `classnames!`
+///
+/// Literals, `Some(literal)`, `None`, `()` and `{}` are resolved at expansion
+/// time; `[...]` takes a nested argument list; anything else may evaluate to a
+/// string or an `Option` of one.
+fn parse_arm(body: Expr) -> proc_macro2::TokenStream {
+    match body {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(_),
+            ..
+        }) => parse_expr(body),
+        Expr::Call(ExprCall { func, mut args, .. })
+            if is_path(&func, "Some")
+                && args.len() == 1
+                && matches!(
+                    args.first(),
+                    Some(Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(_),
+                        ..
+                    }))
+                ) =>
+        {
+            parse_expr(args.pop().unwrap().into_value())
+        }
+        Expr::Path(ref path) if path.path.is_ident("None") => quote! {},
+        Expr::Tuple(ExprTuple { ref elems, .. }) if elems.is_empty() => quote! {},
+        Expr::Block(ExprBlock { ref block, .. }) if block.stmts.is_empty() => quote! {},
+        Expr::Array(_) => push_branch(body),
+        Expr::Paren(ExprParen { expr, .. }) => parse_arm(*expr),
+        Expr::Group(ExprGroup { expr, .. }) => parse_arm(*expr),
+        _ => {
+            let krate = crate_path();
+            quote! {
+                {
+                    use #krate::__private::PushClass as _;
+                    let class_str = #body;
+                    class_str.push_class(&mut classes);
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if `expr` is the single-segment path `name`
+fn is_path(expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Path(path) if path.qself.is_none() && path.path.is_ident(name))
+}
+
+/// One argument of `styles!`, `attrs!` or `data_attrs!`
+enum PairItem {
+    /// `"name" => value`
+    Pair(Box<Expr>, Box<Expr>),
+    /// `(cond, items...)` or `when!(cond, items...)`
+    Conditional(Expr, Vec<PairItem>),
+    /// `..pairs`
+    Spread(Expr),
+}
+
+impl PairItem {
+    fn parse_conditional(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        PairItem::parse_conditional_body(&content)
+    }
+
+    fn parse_when(input: ParseStream) -> syn::Result<Self> {
+        let mac: syn::Macro = input.parse()?;
+        if !mac.path.is_ident("when") {
+            return Err(syn::Error::new_spanned(&mac.path, "expected `when!`"));
+        }
+        mac.parse_body_with(PairItem::parse_conditional_body)
+    }
+
+    fn parse_conditional_body(content: ParseStream) -> syn::Result<Self> {
+        let cond = content.parse()?;
+        content.parse::<Token![,]>()?;
+        let items = Punctuated::<PairItem, Token![,]>::parse_terminated(content)?;
+        if items.is_empty() {
+            return Err(content.error("expected `name => value` pairs after the condition"));
+        }
+        Ok(PairItem::Conditional(cond, items.into_iter().collect()))
+    }
+
+    /// Checks literal names with `check_name`
+    fn check(&self, check_name: fn(&str) -> Result<(), String>) -> syn::Result<()> {
+        match self {
+            PairItem::Pair(name, _) => match &**name {
+                Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(name),
+                    ..
+                }) => check_name(&name.value())
+                    .map_err(|message| syn::Error::new(name.span(), message)),
+                _ => Ok(()),
+            },
+            PairItem::Conditional(_, items) => {
+                items.iter().try_for_each(|item| item.check(check_name))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Expands the item into calls on `builder`, building each pair value with
+    /// `value`
+    fn expand(
+        &self,
+        builder: &Ident,
+        value: fn(&Expr) -> proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self {
+            PairItem::Pair(name, pair_value) => {
+                let pair_value = value(pair_value);
+                quote! {
+                    #builder.set(&(#name), &#pair_value);
+                }
+            }
+            PairItem::Conditional(cond, items) => {
+                let items = items.iter().map(|item| item.expand(builder, value));
+                quote! {
+                    if #cond {
+                        #(#items)*
+                    }
+                }
+            }
+            PairItem::Spread(pairs) => quote! {
+                #builder.extend(&(#pairs));
+            },
+        }
+    }
+}
+
+impl Parse for PairItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![..]) {
+            input.parse::<Token![..]>()?;
+            return Ok(PairItem::Spread(input.parse()?));
+        }
+        let conditional: Option<fn(ParseStream) -> syn::Result<Self>> =
+            if input.peek(syn::token::Paren) {
+                Some(PairItem::parse_conditional)
+            } else if input.peek(Ident) && input.peek2(Token![!]) {
+                Some(PairItem::parse_when)
+            } else {
+                None
+            };
+        if let Some(parse_conditional) = conditional {
+            let fork = input.fork();
+            if let Ok(item) = parse_conditional(&fork) {
+                if fork.is_empty() || fork.peek(Token![,]) {
+                    input.advance_to(&fork);
+                    return Ok(item);
+                }
+            }
+        }
+        let name = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let value = input.parse()?;
+        Ok(PairItem::Pair(Box::new(name), Box::new(value)))
+    }
+}
+
+struct PairsInput {
+    items: Vec<PairItem>,
+}
+
+impl Parse for PairsInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let items = Punctuated::<PairItem, Token![,]>::parse_terminated(input)?;
+        Ok(PairsInput {
+            items: items.into_iter().collect(),
+        })
+    }
+}
+
+/// Rejects literal attribute names that could not be rendered
+fn check_attribute_name(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("attribute names cannot be empty".to_string());
+    }
+    match name.chars().find(|c| {
+        c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '/' | '=' | '<' | '>')
+    }) {
+        Some(c) => Err(format!(
+            "invalid character {:?} in attribute name `{}`",
+            c, name
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Builds an `attrs!` value: arguments of the `classnames!` grammar become the
+/// `String` that `classnames!` builds from them, anything else is an `AttrValue`
+fn attr_value(value: &Expr) -> proc_macro2::TokenStream {
+    match value {
+        Expr::Group(ExprGroup { expr, .. }) => attr_value(expr),
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(_),
+            ..
+        })
+        | Expr::If(_)
+        | Expr::Match(_)
+        | Expr::Block(_) => expand(vec![value.clone()]),
+        Expr::Tuple(ExprTuple { elems, .. }) if matches!(elems.len(), 2 | 3) => {
+            expand(vec![value.clone()])
+        }
+        _ => quote! { (#value) },
+    }
+}
+
+/// Expands `attrs!` and `data_attrs!`, prefixing every pair name with `prefix`
+fn expand_attrs(input: TokenStream, prefix: &str) -> TokenStream {
+    let krate = crate_path();
+    let input = parse_macro_input!(input as PairsInput);
+    if let Err(err) = input
+        .items
+        .iter()
+        .try_for_each(|item| item.check(check_attribute_name))
+    {
+        return err.to_compile_error().into();
+    }
+    let attrs = Ident::new("attrs", proc_macro2::Span::mixed_site());
+    let items = input
+        .items
+        .iter()
+        .map(|item| item.expand(&attrs, attr_value));
+
+    quote! {
+        {
+            let mut #attrs = #krate::__private::AttrsBuilder::new(#prefix);
+            #(#items)*
+            #attrs.finish()
+        }
+    }
+    .into()
+}
+
+/// Builds an inline `style` string from conditional declarations
+///
+/// # Arguments
+/// - `"prop" => value`: a declaration; `value` can be a string, a number or an
+///   `Option` of those, and `None` or empty values are left out
+/// - `(condition, "prop" => value, ...)` or `when!(condition, "prop" => value, ...)`:
+///   declarations added when `condition` holds
+/// - `..declarations`: every declaration of a `prop: value; ...` string, such as
+///   the result of another `styles!`
+///
+/// Declarations are emitted as `prop: value;` separated by single spaces.
+/// Property names are trimmed and lowercased (custom properties keep their
+/// case), values are trimmed. A later declaration of the same property removes
+/// the earlier one and is added at the end, so the cascade order of shorthand
+/// and longhand properties is kept.
+///
+/// Literal property names are checked against the standard CSS properties at
+/// compile time; custom (`--x`) and vendor-prefixed (`-webkit-x`) properties
+/// are always accepted, and a parenthesized name (`("x") => value`) skips the
+/// check.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::styles;
+///
+/// let hidden = true;
+/// let width: Option<u32> = None;
+/// let base = styles!("color" => "red", "margin" => 0);
+///
+/// let style = styles!(
+///     ..base,
+///     "color" => "blue",
+///     "width" => width.map(|w| format!("{}px", w)),
+///     (hidden, "display" => "none", "opacity" => 0),
+///     "--Accent" => " #fff "
+/// );
+/// assert_eq!(style, "margin: 0; color: blue; display: none; opacity: 0; --Accent: #fff;");
+/// ```
+///
+/// Unknown property names are rejected with a suggestion:
+///
+/// ```rust,compile_fail
+/// use classnames_rs::styles;
+///
+/// // unknown CSS property `backgroud-color`; did you mean `background-color`?
+/// let style = styles!("backgroud-color" => "red");
+/// ```
+///
+/// So are empty names:
+///
+/// ```rust,compile_fail
+/// use classnames_rs::styles;
+///
+/// // CSS property names cannot be empty
+/// let style = styles!("" => "red");
+/// ```
+#[proc_macro]
+pub fn styles(input: TokenStream) -> TokenStream {
+    let krate = crate_path();
+    let input = parse_macro_input!(input as PairsInput);
+    if let Err(err) = input
+        .items
+        .iter()
+        .try_for_each(|item| item.check(css_properties::check_property))
+    {
+        return err.to_compile_error().into();
+    }
+    let styles = Ident::new("styles", proc_macro2::Span::mixed_site());
+    let items = input
+        .items
+        .iter()
+        .map(|item| item.expand(&styles, |value| quote! { (#value) }));
+
+    quote! {
+        {
+            let mut #styles = #krate::__private::StyleBuilder::new();
+            #(#items)*
+            #styles.finish()
+        }
+    }
+    .into()
+}
+
+/// Builds an ordered list of HTML attributes from conditional `name => value` pairs
+///
+/// # Arguments
+/// - `"name" => value`: an attribute. A `value` in the grammar of
+///   [`classnames!`] (a string literal, `(cond, "a")`, `(cond, "a", "b")`, `if`,
+///   `match` or a block) is built like a `classnames!` argument. Any other
+///   `value` can be a string, a number, a `bool` or an `Option` of those. `true`
+///   adds a boolean attribute (`data-disabled`), while `false`, `None` and empty
+///   strings leave the attribute out, so `when!`, `maybe!` and `choose!` work as
+///   values
+/// - `(condition, "name" => value, ...)` or `when!(condition, "name" => value, ...)`:
+///   attributes added when `condition` holds
+/// - `..attributes`: every attribute of another `Attributes`
+///
+/// Returns `classnames_rs::Attributes`, which keeps the attributes in order and
+/// renders them as an escaped HTML attribute string. Names are trimmed and
+/// lowercased; a later pair with the same name replaces the earlier value in
+/// place. Literal names that could not be rendered are rejected at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{attrs, maybe, when};
+///
+/// let open = true;
+/// let disabled = false;
+/// let orientation: Option<&str> = Some("vertical");
+///
+/// let attributes = attrs!(
+///     "data-state" => (open, "open"),
+///     "data-orientation" => maybe!(orientation),
+///     (disabled, "data-disabled" => true, "aria-disabled" => "true"),
+///     "title" => "Tom & Jerry"
+/// );
+/// assert_eq!(
+///     attributes.to_string(),
+///     r#"data-state="open" data-orientation="vertical" title="Tom &amp; Jerry""#
+/// );
+/// ```
+#[proc_macro]
+pub fn attrs(input: TokenStream) -> TokenStream {
+    expand_attrs(input, "")
+}
+
+/// Like `attrs!`, with `data-` prepended to every pair name
+///
+/// Spread attributes (`..attributes`) are added as they are.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{choose, data_attrs};
+///
+/// let open = false;
+/// let disabled = true;
+///
+/// let attributes = data_attrs!(
+///     "state" => choose!(open, "open", "closed"),
+///     "disabled" => disabled,
+///     "orientation" => "horizontal"
+/// );
+/// assert_eq!(
+///     attributes.iter().collect::<Vec<_>>(),
+///     [("data-state", "closed"), ("data-disabled", ""), ("data-orientation", "horizontal")]
+/// );
+/// assert_eq!(
+///     attributes.to_string(),
+///     r#"data-state="closed" data-disabled data-orientation="horizontal""#
+/// );
+/// ```
+#[proc_macro]
+pub fn data_attrs(input: TokenStream) -> TokenStream {
+    expand_attrs(input, "data-")
+}
+
+/// Conditional class name selection macro for dynamically choosing different class names based on conditions
+///
+/// # Description
+/// - Accepts a conditional expression and two class name values; either value
+///   may be a `[...]` list of `classnames!` arguments
+/// - Returns the corresponding class name based on whether the condition is true or false
+/// - Borrows the selected literal when both values are literals, see below
+/// - Automatically handles excess whitespace in class names
+/// - Can be combined with other class name macros
+///
+/// # Parameters
+/// - `condition`: Any expression that evaluates to a boolean value
+/// - `true_value`: Class name returned when condition is true
+/// - `false_value`: Class name returned when condition is false
+///
+/// When both values are string literals the result is a `Cow<'static, str>`
+/// borrowing the selected literal, normalized at compile time; otherwise it is
+/// a `String`.
+///
+/// # Examples
+///
+/// ### Basic usage:
+/// ```rust
+/// use classnames_rs::choose;
+///
+/// let is_active = true;
+/// let class = choose!(is_active, "active", "inactive");
+/// assert_eq!(class, "active");
+/// ```
+///
+/// ### Combined with classnames!:
+/// ```rust
+/// use classnames_rs::{classnames, choose};
+///
+/// let is_primary = true;
+/// let result = classnames!(
+///     "btn",
+///     choose!(is_primary, "btn-primary", "btn-secondary")
+/// );
+/// assert_eq!(result, "btn btn-primary");
+/// ```
+///
+/// ### Complex condition evaluation:
+/// ```rust
+/// use classnames_rs::{classnames, choose};
+///
+/// let score = 85;
+/// let result = classnames!(
+///     "grade",
+///     choose!(score >= 80, "excellent", "normal")
+/// );
+/// assert_eq!(result, "grade excellent");
+/// ```
+///
+/// ### Lists of classes:
+/// ```rust
+/// use classnames_rs::{choose, when};
+///
+/// let is_primary = false;
+/// let is_large = true;
+/// let class = choose!(
+///     is_primary,
+///     ["btn-primary", "text-white"],
+///     ["btn-secondary", when!(is_large, "btn-lg")]
+/// );
+/// assert_eq!(class, "btn-secondary btn-lg");
+/// ```
+///
+/// ### Nested usage:
+/// ```rust
+/// use classnames_rs::{classnames, choose};
+///
+/// let is_dark = true;
+/// let is_active = false;
+/// let result = classnames!(
+///     "theme",
+///     choose!(is_dark, "dark", "light"),
+///     choose!(is_active, "active", "inactive")
+/// );
+/// assert_eq!(result, "theme dark inactive");
+/// ```
+#[proc_macro]
+pub fn choose(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ClassNamesInput);
+    let exprs: Vec<_> = input.exprs.into_iter().collect();
+
+    if exprs.len() != 3 {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "choose! macro requires exactly three arguments: condition, true_value, false_value",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let cond = &exprs[0];
+    if let Some(values) = static_branches(&exprs[1..]) {
+        let (true_val, false_val) = (&values[0], &values[1]);
+        return quote! {
+            ::std::borrow::Cow::<'static, str>::Borrowed(if #cond { #true_val } else { #false_val })
+        }
+        .into();
+    }
+    let true_val = branch_string(&exprs[1]);
+    let false_val = branch_string(&exprs[2]);
+
+    // Wrap the result in a string expression
+    quote! {
+        ({
+            let result = if #cond {
+                #true_val
+            } else {
+                #false_val
+            };
+            result
+        })
+    }
+    .into()
+}
+
+/// Input of `theme!`: `value, Theme { name: classes, ... }`, or
+/// `value, { name: classes, ... }` for the crate's own `Theme`
+struct ThemeInput {
+    value: Expr,
+    path: Option<syn::Path>,
+    entries: Vec<(Ident, Expr)>,
+}
+
+impl Parse for ThemeInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let value = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let path = if input.peek(syn::token::Brace) {
+            None
+        } else {
+            Some(input.call(syn::Path::parse_mod_style)?)
+        };
+        let content;
+        syn::braced!(content in input);
+        let mut entries: Vec<(Ident, Expr)> = Vec::new();
+        while !content.is_empty() {
+            let name: Ident = content.parse()?;
+            if entries.iter().any(|(existing, _)| *existing == name) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("duplicate theme `{}`", name),
+                ));
+            }
+            content.parse::<Token![:]>()?;
+            entries.push((name, content.parse()?));
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Token![,]>()?;
+        }
+        input.parse::<Option<Token![,]>>()?;
+        Ok(ThemeInput {
+            value,
+            path,
+            entries,
+        })
+    }
+}
+
+/// Converts a `snake_case` theme name to its `UpperCamelCase` variant name
+fn variant_name(name: &Ident) -> Ident {
+    let variant: String = name
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    Ident::new(&variant, name.span())
+}
+
+/// Theme-aware helper macro: selects the classes of the current theme
+///
+/// Takes a theme value and the classes of every variant of its enum, keyed by
+/// `snake_case` variant name. Leaving out the enum path, as in
+/// `theme!(current, { light: ..., dark: ..., high_contrast: ... })`, selects
+/// from `classnames_rs::Theme`. Like `choose!`, values may be class strings or
+/// `[...]` lists of `classnames!` arguments. The expansion is a `match` on the
+/// enum, so leaving out a theme, or naming one the enum doesn't have, is a
+/// compile error. Like `choose!`, it returns a `Cow<'static, str>` borrowing
+/// the selected literal when every value is a string literal, and a `String`
+/// otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{classnames, theme};
+///
+/// #[derive(Clone, Copy)]
+/// enum Theme {
+///     Light,
+///     Dark,
+///     HighContrast,
+/// }
+///
+/// let current = Theme::HighContrast;
+/// let class = classnames!(
+///     "card",
+///     theme!(current, Theme {
+///         light: "bg-white text-gray-900",
+///         dark: "bg-gray-900 text-gray-100",
+///         high_contrast: ["bg-black", "text-yellow-300", "border-2"],
+///     })
+/// );
+/// assert_eq!(class, "card bg-black text-yellow-300 border-2");
+/// ```
+///
+/// With the crate's `Theme`:
+///
+/// ```rust
+/// use classnames_rs::{theme, Theme};
+///
+/// let class = theme!(Theme::Dark, { light: "bg-white", dark: "bg-gray-900", high_contrast: "bg-black" });
+/// assert_eq!(class, "bg-gray-900");
+/// ```
+///
+/// Every theme needs an entry:
+///
+/// ```rust,compile_fail
+/// use classnames_rs::theme;
+///
+/// enum Theme { Light, Dark, HighContrast }
+///
+/// // non-exhaustive patterns: `Theme::HighContrast` not covered
+/// let class = theme!(Theme::Dark, Theme { light: "bg-white", dark: "bg-gray-900" });
+/// ```
+///
+/// ```rust,compile_fail
+/// use classnames_rs::{theme, Theme};
+///
+/// // non-exhaustive patterns: `classnames_rs::Theme::HighContrast` not covered
+/// let class = theme!(Theme::Dark, { light: "bg-white", dark: "bg-gray-900" });
+/// ```
+#[proc_macro]
+pub fn theme(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ThemeInput);
+    let path = match &input.path {
+        Some(path) => quote! { #path },
+        None => {
+            let krate = crate_path();
+            quote! { #krate::Theme }
+        }
+    };
+    let value = &input.value;
+
+    if let Some(values) = static_branches(input.entries.iter().map(|(_, classes)| classes)) {
+        let arms = input
+            .entries
+            .iter()
+            .zip(values)
+            .map(|((name, _), classes)| {
+                let variant = variant_name(name);
+                quote! { #path::#variant => #classes, }
+            });
+        return quote! {
+            ::std::borrow::Cow::<'static, str>::Borrowed(match #value {
+                #(#arms)*
+            })
+        }
+        .into();
+    }
+
+    let mut arms = Vec::with_capacity(input.entries.len());
+    for (name, classes) in input.entries {
+        let variant = variant_name(&name);
+        let classes = branch_string(&classes);
+        arms.push(quote! { #path::#variant => #classes, });
+    }
+
+    quote! {
+        ({
+            let result = match #value {
+                #(#arms)*
+            };
+            result
+        })
+    }
+    .into()
+}
+
+/// Helper macro for handling optional types
+///
+/// # Examples
+/// ```rust
+/// use classnames_rs::{classnames, maybe};
+///
+/// let optional_class: Option<&str> = Some("highlight");
+/// let result = classnames!(
+///     "base",
+///     maybe!(optional_class)
+/// );
+/// assert_eq!(result, "base highlight");
+///
+/// let no_class: Option<&str> = None;
+/// let result = classnames!(
+///     "base",
+///     maybe!(no_class)
+/// );
+/// assert_eq!(result, "base");
+/// ```
+#[proc_macro]
+pub fn maybe(input: TokenStream) -> TokenStream {
+    let krate = crate_path();
+    let input = parse_macro_input!(input as ClassNamesInput);
+    let exprs: Vec<_> = input.exprs.into_iter().collect();
+
+    if exprs.len() != 1 {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "maybe! macro requires exactly one argument",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let value = &exprs[0];
+    quote! {
+        ({
+            match #value {
+                Some(value) => #krate::__private::normalize(&value.to_string()),
+                None => ::std::string::String::new()
+            }
+        })
+    }
+    .into()
+}
+
+/// Conditional helper macro for cleaner syntax
+///
+/// Like `choose!`, a string literal value gives a `Cow<'static, str>` that
+/// borrows it (or the empty string); any other value gives a `String`.
+///
+/// # Examples
+/// ```rust
+/// use classnames_rs::{classnames, when};
+///
+/// let is_active = true;
+/// let result = classnames!(
+///     "btn",
+///     when!(is_active, "active")  // More concise syntax
+/// );
+/// assert_eq!(result, "btn active");
+///
+/// let is_disabled = false;
+/// let result = classnames!(
+///     "btn",
+///     when!(is_disabled, "disabled")
+/// );
+/// assert_eq!(result, "btn");
+/// ```
+#[proc_macro]
+pub fn when(input: TokenStream) -> TokenStream {
+    let krate = crate_path();
+    let input = parse_macro_input!(input as ClassNamesInput);
+    let exprs: Vec<_> = input.exprs.into_iter().collect();
+
+    if exprs.len() != 2 {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "when! macro requires exactly two arguments: condition and value",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let cond = &exprs[0];
+    let value = &exprs[1];
+    if let Some(values) = static_branches([value]) {
+        let value = &values[0];
+        return quote! {
+            ::std::borrow::Cow::<'static, str>::Borrowed(if #cond { #value } else { "" })
+        }
+        .into();
+    }
+
+    quote! {
+        ({
+            if #cond {
+                #krate::__private::normalize(&#value.to_string())
+            } else {
+                ::std::string::String::new()
+            }
+        })
+    }
+    .into()
+}
+
+/// Public macro for formatting class names and normalizing whitespace
+///
+/// # Examples
+/// ```rust
+/// use classnames_rs::pretty_classname;
+///
+/// let messy = "class1   class2\n\t  class3";
+/// assert_eq!(pretty_classname!(messy), "class1 class2 class3");
+///
+/// let with_tabs = "\tprimary\t\tsecondary\t";
+/// assert_eq!(pretty_classname!(with_tabs), "primary secondary");
+/// ```
+#[proc_macro]
+pub fn pretty_classname(input: TokenStream) -> TokenStream {
+    let krate = crate_path();
+    let input = parse_macro_input!(input as ClassNamesInput);
+    let exprs = match rewrite_literals(input.exprs) {
+        Ok(exprs) => exprs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let expr = &exprs[0];
+
+    quote! {
+        {
+            #krate::__private::normalize(&#expr.to_string())
+        }
+    }
+    .into()
+}
+
+/// Reactive variant of `classnames!` for Leptos (`leptos` feature of `classnames-rs`)
+///
+/// Accepts the same arguments as `classnames!`, but conditions may also be
+/// `bool` signals (`ReadSignal`, `RwSignal`, `Memo`, `Signal` and their `Arc`
+/// variants). The result is a `ReactiveClasses` value that Leptos re-evaluates
+/// whenever one of those signals changes. Conditions of tuples, `if`
+/// expressions, `when!` and `choose!` are all tracked.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use classnames_rs::{reactive_classnames, when};
+/// use leptos::prelude::*;
+///
+/// let (active, set_active) = signal(true);
+/// let disabled = RwSignal::new(false);
+/// let mut classes = reactive_classnames!(
+///     "btn",
+///     (active, "active"),
+///     when!(disabled, "disabled"),
+///     if active.get() { "on" } else { "off" }
+/// );
+/// assert_eq!(classes.get(), "btn active on");
+///
+/// set_active.set(false);
+/// disabled.set(true);
+/// assert_eq!(classes.get(), "btn disabled off");
+/// ```
+#[proc_macro]
+pub fn reactive_classnames(input: TokenStream) -> TokenStream {
+    let krate = crate_path();
+    let input = parse_macro_input!(input as ClassNamesInput);
+    let exprs = input.exprs.into_iter().map(track_conditions);
+
+    quote! {
+        #krate::leptos::ReactiveClasses::new(move || {
+            // `if` branches are re-emitted as nested blocks
+            #[allow(unused_braces)]
+            let classes = #krate::classnames!(#(#exprs),*);
+            classes
+        })
+    }
+    .into()
+}
+
+/// Routes every condition of a `classnames!` argument through
+/// `classnames_rs::leptos::evaluate`, so signals can be used as conditions
+fn track_conditions(expr: Expr) -> Expr {
+    let krate = crate_path();
+    let track = |cond: &Expr| -> Expr { syn::parse_quote!(#krate::leptos::evaluate(&(#cond))) };
+
+    match expr {
+        Expr::Tuple(mut tuple) if tuple.elems.len() == 2 || tuple.elems.len() == 3 => {
+            tuple.elems[0] = track(&tuple.elems[0]);
+            Expr::Tuple(tuple)
+        }
+        Expr::If(mut expr_if) => {
+            *expr_if.cond = track(&expr_if.cond);
+            if let Some((_, else_expr)) = expr_if.else_branch.as_mut() {
+                **else_expr = track_conditions((**else_expr).clone());
+            }
+            Expr::If(expr_if)
+        }
+        Expr::Macro(mut expr_macro) => {
+            let is_conditional = expr_macro
+                .mac
+                .path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == "when" || s.ident == "choose");
+            if is_conditional {
+                if let Ok(mut args) = expr_macro
+                    .mac
+                    .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                {
+                    if let Some(cond) = args.first_mut() {
+                        *cond = track(cond);
+                    }
+                    expr_macro.mac.tokens = quote!(#args);
+                }
+            }
+            Expr::Macro(expr_macro)
+        }
+        other => other,
+    }
+}
+
+/// Minifies a literal class string for `classnames_concat!`
+///
+/// Expands to the argument unchanged unless the `minify` feature is enabled
+/// and the crate is listed in `CLASSNAMES_MINIFY`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __minify(input: TokenStream) -> TokenStream {
+    let expr = parse_macro_input!(input as Expr);
+    match rewrite_literals(vec![expr]) {
+        Ok(exprs) => {
+            let expr = &exprs[0];
+            quote!(#expr).into()
+        }
+        Err(err) => err.to_compile_error().into(),
+    }
+}
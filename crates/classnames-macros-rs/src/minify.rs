@@ -0,0 +1,220 @@
+//! Compile-time class name minification (`minify` feature)
+//!
+//! The feature only compiles the rewriting in. A crate opts in by naming
+//! itself in `CLASSNAMES_MINIFY`, usually from its build script, so other
+//! crates in the same build keep their class names even though cargo enables
+//! the feature for all of them.
+//!
+//! Short names come from `$OUT_DIR/classnames-manifest.json`, which the build
+//! script of the crate writes with `classnames_extract_rs::minify::write_manifest`
+//! from every class its sources use. The macros only read it, so the names
+//! don't depend on the order they are expanded in.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use syn::punctuated::Punctuated;
+use syn::{Block, Expr, ExprLit, ExprMacro, Lit, LitStr, Stmt, Token};
+
+/// File name of the manifest in `OUT_DIR`
+const MANIFEST_FILE: &str = "classnames-manifest.json";
+
+/// Environment variable listing the crates to minify, separated by commas,
+/// e.g. set by a build script with `cargo:rustc-env=CLASSNAMES_MINIFY=my_app`
+///
+/// Names are matched against `CARGO_CRATE_NAME`, so they use underscores, and
+/// an integration test target is named after its file.
+const CRATES_VAR: &str = "CLASSNAMES_MINIFY";
+
+/// Short names by class
+type Manifest = BTreeMap<String, String>;
+
+/// Returns `true` if the crate being compiled is listed in `CLASSNAMES_MINIFY`
+fn enabled() -> bool {
+    let (Ok(crates), Ok(current)) = (std::env::var(CRATES_VAR), std::env::var("CARGO_CRATE_NAME"))
+    else {
+        return false;
+    };
+    crates.split(',').any(|name| name.trim() == current)
+}
+
+/// Reads the manifest the build script of the crate being compiled wrote
+fn load_manifest() -> Result<Manifest, String> {
+    const HINT: &str = "write it from the build script of this crate with \
+                        `classnames_extract_rs::minify::write_manifest`";
+
+    let out_dir = std::env::var_os("OUT_DIR")
+        .ok_or_else(|| format!("the `minify` feature reads a class name manifest; {}", HINT))?;
+    let path = PathBuf::from(out_dir).join(MANIFEST_FILE);
+    let content = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "cannot read class name manifest {}: {}; {}",
+            path.display(),
+            e,
+            HINT
+        )
+    })?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("invalid class name manifest {}: {}", path.display(), e))
+}
+
+/// Rewrites every class name token of `value` to its short name
+fn minify_classes(value: &str, manifest: &Manifest) -> Result<String, String> {
+    let mut result = String::with_capacity(value.len());
+    for token in classnames_core_rs::tokens(value) {
+        let short = manifest.get(token).ok_or_else(|| {
+            format!(
+                "class `{}` is missing from the class name manifest; \
+                 pass the file that uses it to `write_manifest` in the build script",
+                token
+            )
+        })?;
+        classnames_core_rs::push_normalized(&mut result, short);
+    }
+    Ok(result)
+}
+
+/// Rewrites string literals in class positions of `classnames!` arguments,
+/// if the crate being compiled opted in
+///
+/// Conditions are left untouched; only values that end up in the class list
+/// (literals, tuple values, `if`/block results, `match` arms and the values of
+/// nested `when!`, `choose!`, `maybe!` and `theme!` calls) are minified. Those
+/// helpers don't minify on their own, since they may also build values outside
+/// a class list.
+pub(crate) fn rewrite_literals(exprs: Vec<Expr>) -> syn::Result<Vec<Expr>> {
+    if !enabled() || exprs.is_empty() {
+        return Ok(exprs);
+    }
+    let manifest = load_manifest().map_err(|e| syn::Error::new_spanned(&exprs[0], e))?;
+    exprs
+        .into_iter()
+        .map(|expr| rewrite(expr, &manifest))
+        .collect()
+}
+
+fn rewrite(expr: Expr, manifest: &Manifest) -> syn::Result<Expr> {
+    Ok(match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s),
+            attrs,
+        }) => {
+            let value =
+                minify_classes(&s.value(), manifest).map_err(|e| syn::Error::new(s.span(), e))?;
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(LitStr::new(&value, s.span())),
+                attrs,
+            })
+        }
+        Expr::Tuple(mut tuple) if tuple.elems.len() == 2 || tuple.elems.len() == 3 => {
+            for elem in tuple.elems.iter_mut().skip(1) {
+                *elem = rewrite(elem.clone(), manifest)?;
+            }
+            Expr::Tuple(tuple)
+        }
+        Expr::If(mut expr_if) => {
+            rewrite_block(&mut expr_if.then_branch, manifest)?;
+            if let Some((_, else_expr)) = expr_if.else_branch.as_mut() {
+                **else_expr = rewrite((**else_expr).clone(), manifest)?;
+            }
+            Expr::If(expr_if)
+        }
+        Expr::Block(mut expr_block) => {
+            rewrite_block(&mut expr_block.block, manifest)?;
+            Expr::Block(expr_block)
+        }
+        Expr::Match(mut expr_match) => {
+            for arm in expr_match.arms.iter_mut() {
+                *arm.body = rewrite((*arm.body).clone(), manifest)?;
+            }
+            Expr::Match(expr_match)
+        }
+        Expr::Array(mut array) => {
+            for elem in array.elems.iter_mut() {
+                *elem = rewrite(elem.clone(), manifest)?;
+            }
+            Expr::Array(array)
+        }
+        Expr::Call(mut call) if is_some(&call.func) && call.args.len() == 1 => {
+            for arg in call.args.iter_mut() {
+                *arg = rewrite(arg.clone(), manifest)?;
+            }
+            Expr::Call(call)
+        }
+        Expr::Paren(mut paren) => {
+            *paren.expr = rewrite((*paren.expr).clone(), manifest)?;
+            Expr::Paren(paren)
+        }
+        Expr::Group(mut group) => {
+            *group.expr = rewrite((*group.expr).clone(), manifest)?;
+            Expr::Group(group)
+        }
+        Expr::Macro(expr_macro) => Expr::Macro(rewrite_macro(expr_macro, manifest)?),
+        other => other,
+    })
+}
+
+/// Rewrites the class values of a nested `when!`, `choose!`, `maybe!` or
+/// `theme!` call, leaving other macros alone
+fn rewrite_macro(mut expr_macro: ExprMacro, manifest: &Manifest) -> syn::Result<ExprMacro> {
+    let Some(name) = expr_macro.mac.path.segments.last() else {
+        return Ok(expr_macro);
+    };
+    // Number of leading arguments that aren't classes
+    let skip = match name.ident.to_string().as_str() {
+        "when" | "choose" => 1,
+        "maybe" => 0,
+        "theme" => return rewrite_theme(expr_macro, manifest),
+        _ => return Ok(expr_macro),
+    };
+    // Malformed calls are left for the macro itself to report
+    let Ok(mut args) = expr_macro
+        .mac
+        .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+    else {
+        return Ok(expr_macro);
+    };
+
+    for arg in args.iter_mut().skip(skip) {
+        *arg = rewrite(arg.clone(), manifest)?;
+    }
+    expr_macro.mac.tokens = quote::quote!(#args);
+    Ok(expr_macro)
+}
+
+/// Rewrites the classes of every entry of a nested `theme!` call, with or
+/// without an enum path
+fn rewrite_theme(mut expr_macro: ExprMacro, manifest: &Manifest) -> syn::Result<ExprMacro> {
+    let Ok(input) = expr_macro.mac.parse_body::<crate::ThemeInput>() else {
+        return Ok(expr_macro);
+    };
+    let crate::ThemeInput {
+        value,
+        path,
+        entries,
+    } = input;
+    let mut names = Vec::with_capacity(entries.len());
+    let mut classes = Vec::with_capacity(entries.len());
+    for (name, entry) in entries {
+        names.push(name);
+        classes.push(rewrite(entry, manifest)?);
+    }
+    expr_macro.mac.tokens = quote::quote! {
+        #value, #path { #(#names: #classes),* }
+    };
+    Ok(expr_macro)
+}
+
+/// Returns `true` for the `Some` constructor
+fn is_some(func: &Expr) -> bool {
+    matches!(func, Expr::Path(path) if path.path.is_ident("Some"))
+}
+
+/// Rewrites the tail expression of a block
+fn rewrite_block(block: &mut Block, manifest: &Manifest) -> syn::Result<()> {
+    if let Some(Stmt::Expr(tail, None)) = block.stmts.last_mut() {
+        *tail = rewrite(tail.clone(), manifest)?;
+    }
+    Ok(())
+}
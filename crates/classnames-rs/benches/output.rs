@@ -0,0 +1,145 @@
+//! Compares the `String` output of `classnames!`, the inline buffer of
+//! `small_classnames!` and a `Vec<String>` + `join` baseline, plus the static
+//! lookup table with `String` and `Cow` outputs against the same calls built
+//! piece by piece.
+//!
+//! Run with `cargo bench -p classnames-rs --features smallstr --bench output`
+
+use std::hint::black_box;
+
+use classnames_rs::{classnames, cow_classnames, maybe, small_classnames, when};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// The expansion `classnames!` used before it wrote into a single buffer
+fn join_baseline(is_active: bool, is_disabled: bool, size: Option<&str>) -> String {
+    let mut classes = Vec::new();
+    classes.push("btn".to_string());
+    classes.push("btn-primary".to_string());
+    if is_active {
+        classes.push("active".to_string());
+    }
+    if is_disabled {
+        classes.push("disabled".to_string());
+    }
+    if let Some(size) = size {
+        classes.push(size.to_string());
+    }
+    classes
+        .iter()
+        .flat_map(|class| class.split_whitespace())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bench_short(c: &mut Criterion) {
+    let mut group = c.benchmark_group("short");
+    group.bench_function("join", |b| {
+        b.iter(|| join_baseline(black_box(true), black_box(false), black_box(Some("lg"))))
+    });
+    group.bench_function("classnames", |b| {
+        b.iter(|| {
+            let (is_active, is_disabled, size) = black_box((true, false, Some("lg")));
+            classnames!(
+                "btn btn-primary",
+                (is_active, "active"),
+                (is_disabled, "disabled"),
+                maybe!(size)
+            )
+        })
+    });
+    group.bench_function("small_classnames", |b| {
+        b.iter(|| {
+            let (is_active, is_disabled, size) = black_box((true, false, Some("lg")));
+            small_classnames!(
+                "btn btn-primary",
+                (is_active, "active"),
+                (is_disabled, "disabled"),
+                maybe!(size)
+            )
+        })
+    });
+    group.finish();
+}
+
+fn bench_long(c: &mut Criterion) {
+    let mut group = c.benchmark_group("long");
+    group.bench_function("classnames", |b| {
+        b.iter(|| {
+            let (is_open, is_dense) = black_box((true, false));
+            classnames!(
+                "flex items-center justify-between rounded-lg border border-gray-200",
+                (is_open, "bg-white shadow-lg ring-1 ring-black/5"),
+                when!(is_dense, "px-2 py-1"),
+                (!is_dense, "px-4 py-2")
+            )
+        })
+    });
+    group.bench_function("small_classnames", |b| {
+        b.iter(|| {
+            let (is_open, is_dense) = black_box((true, false));
+            small_classnames!(
+                "flex items-center justify-between rounded-lg border border-gray-200",
+                (is_open, "bg-white shadow-lg ring-1 ring-black/5"),
+                when!(is_dense, "px-2 py-1"),
+                (!is_dense, "px-4 py-2")
+            )
+        })
+    });
+    group.finish();
+}
+
+/// Same classes as the literal of `bench_static`; a constant isn't a literal,
+/// so calls using it are built piece by piece instead of from the table
+const BUTTON: &str = "btn btn-primary";
+
+fn bench_static(c: &mut Criterion) {
+    let mut group = c.benchmark_group("static");
+    group.bench_function("classnames_pieces", |b| {
+        b.iter(|| {
+            let (is_active, is_disabled, is_large) = black_box((true, false, true));
+            classnames!(
+                BUTTON,
+                (is_active, "active"),
+                (is_disabled, "disabled"),
+                (is_large, "btn-lg", "btn-md")
+            )
+        })
+    });
+    group.bench_function("classnames", |b| {
+        b.iter(|| {
+            let (is_active, is_disabled, is_large) = black_box((true, false, true));
+            classnames!(
+                "btn btn-primary",
+                (is_active, "active"),
+                (is_disabled, "disabled"),
+                (is_large, "btn-lg", "btn-md")
+            )
+        })
+    });
+    group.bench_function("cow_classnames_pieces", |b| {
+        b.iter(|| {
+            let (is_active, is_disabled, is_large) = black_box((true, false, true));
+            cow_classnames!(
+                BUTTON,
+                (is_active, "active"),
+                (is_disabled, "disabled"),
+                (is_large, "btn-lg", "btn-md")
+            )
+        })
+    });
+    group.bench_function("cow_classnames", |b| {
+        b.iter(|| {
+            let (is_active, is_disabled, is_large) = black_box((true, false, true));
+            cow_classnames!(
+                "btn btn-primary",
+                (is_active, "active"),
+                (is_disabled, "disabled"),
+                (is_large, "btn-lg", "btn-md")
+            )
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_short, bench_long, bench_static);
+criterion_main!(benches);
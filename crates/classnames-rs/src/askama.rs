@@ -0,0 +1,103 @@
+//! Askama integration (`askama` feature)
+//!
+//! `classnames!` returns a `String`, which askama escapes like any other
+//! value. For class lists built in the template itself, the `|classnames`
+//! filter joins the classes of a class→bool map or list whose value is `true`.
+//! Askama looks custom filters up in a `filters` module next to the template:
+//!
+//! ```rust
+//! use askama::Template;
+//!
+//! mod filters {
+//!     pub use classnames_rs::askama::classnames;
+//! }
+//!
+//! #[derive(Template)]
+//! #[template(source = r#"<li class="{{ flags|classnames }}">{{ name }}</li>"#, ext = "html")]
+//! struct Item<'a> {
+//!     name: &'a str,
+//!     flags: Vec<(&'a str, bool)>,
+//! }
+//!
+//! let item = Item {
+//!     name: "Inbox",
+//!     flags: vec![("item", true), ("selected", true), ("disabled", false)],
+//! };
+//! assert_eq!(item.render().unwrap(), r#"<li class="item selected">Inbox</li>"#);
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use crate::__private::push_normalized;
+
+/// Class→bool collections accepted by the [`classnames`] filter
+pub trait ClassConditions {
+    /// Appends the classes whose condition is `true` to `out`
+    fn push_classes(&self, out: &mut String);
+}
+
+impl<K: AsRef<str>> ClassConditions for [(K, bool)] {
+    fn push_classes(&self, out: &mut String) {
+        for (class, enabled) in self {
+            if *enabled {
+                push_normalized(out, class.as_ref());
+            }
+        }
+    }
+}
+
+impl<K: AsRef<str>, const N: usize> ClassConditions for [(K, bool); N] {
+    fn push_classes(&self, out: &mut String) {
+        self.as_slice().push_classes(out);
+    }
+}
+
+impl<K: AsRef<str>> ClassConditions for Vec<(K, bool)> {
+    fn push_classes(&self, out: &mut String) {
+        self.as_slice().push_classes(out);
+    }
+}
+
+impl<K: AsRef<str>> ClassConditions for BTreeMap<K, bool> {
+    fn push_classes(&self, out: &mut String) {
+        for (class, enabled) in self {
+            if *enabled {
+                push_normalized(out, class.as_ref());
+            }
+        }
+    }
+}
+
+/// Classes are sorted, so the output does not depend on the hash order
+impl<K: AsRef<str>, S: BuildHasher> ClassConditions for HashMap<K, bool, S> {
+    fn push_classes(&self, out: &mut String) {
+        let mut classes: Vec<&str> = self
+            .iter()
+            .filter(|(_, enabled)| **enabled)
+            .map(|(class, _)| class.as_ref())
+            .collect();
+        classes.sort_unstable();
+        for class in classes {
+            push_normalized(out, class);
+        }
+    }
+}
+
+impl<T: ClassConditions + ?Sized> ClassConditions for &T {
+    fn push_classes(&self, out: &mut String) {
+        (**self).push_classes(out);
+    }
+}
+
+/// Askama filter joining the enabled classes of a class→bool map or list
+///
+/// The result is escaped by askama like any other filter output.
+pub fn classnames<T: ClassConditions + ?Sized>(
+    conditions: &T,
+    _: &dyn ::askama::Values,
+) -> ::askama::Result<String> {
+    let mut out = String::new();
+    conditions.push_classes(&mut out);
+    Ok(out)
+}
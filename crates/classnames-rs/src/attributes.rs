@@ -0,0 +1,266 @@
+//! Ordered HTML attribute lists built by `attrs!` and `data_attrs!`
+
+use std::borrow::Cow;
+use std::fmt;
+
+/// An ordered list of HTML attributes, one value per name
+///
+/// Built by [`attrs!`](crate::attrs) and [`data_attrs!`](crate::data_attrs).
+/// An empty value stands for a boolean attribute that is present
+/// (`data-disabled`). [`Display`](fmt::Display) renders the list as escaped HTML.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::attrs;
+///
+/// let open = true;
+/// let attributes = attrs!("data-state" => if open { "open" } else { "closed" }, "title" => "<Menu>");
+/// assert_eq!(
+///     attributes.iter().collect::<Vec<_>>(),
+///     [("data-state", "open"), ("title", "<Menu>")]
+/// );
+/// assert_eq!(attributes.to_string(), r#"data-state="open" title="&lt;Menu&gt;""#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attributes {
+    pairs: Vec<(String, String)>,
+}
+
+impl Attributes {
+    /// Creates an empty attribute list
+    pub const fn new() -> Self {
+        Attributes { pairs: Vec::new() }
+    }
+
+    /// Sets an attribute, replacing the value of an earlier one with the same name
+    ///
+    /// Names are trimmed and lowercased. Names that are empty or contain
+    /// whitespace, quotes, `/`, `=`, `<`, `>` or control characters are ignored.
+    /// A value that leaves the attribute out (`false`, `None`, an empty string)
+    /// removes an earlier one with the same name.
+    pub fn set<V: AttrValue + ?Sized>(&mut self, name: &str, value: &V) {
+        let name = name.trim();
+        if !is_valid_name(name) {
+            return;
+        }
+        let name = name.to_ascii_lowercase();
+        match value.attr_value() {
+            Some(value) => self.insert(name, value.into_owned()),
+            None => self.pairs.retain(|(n, _)| *n != name),
+        }
+    }
+
+    /// Sets every attribute of `other`, in order
+    ///
+    /// Boolean attributes of `other` stay present.
+    pub fn extend(&mut self, other: &Attributes) {
+        for (name, value) in other {
+            self.insert(name.to_owned(), value.to_owned());
+        }
+    }
+
+    /// Replaces the value of `name`, or appends it, without validating either
+    fn insert(&mut self, name: String, value: String) {
+        match self.pairs.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.pairs.push((name, value)),
+        }
+    }
+
+    /// Returns the value of an attribute
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Iterates over the `(name, value)` pairs in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+
+    /// Returns the number of attributes
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns `true` if there are no attributes
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// Returns the `(name, value)` pairs
+    pub fn into_vec(self) -> Vec<(String, String)> {
+        self.pairs
+    }
+
+    /// Renders the attributes as `name="value"`, separated by spaces
+    ///
+    /// Values are HTML-escaped; empty values render as a bare name.
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Attributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, value)) in self.pairs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(name)?;
+            if !value.is_empty() {
+                f.write_str("=\"")?;
+                write_escaped(f, value)?;
+                f.write_str("\"")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> IntoIterator for &'a Attributes {
+    type Item = (&'a str, &'a str);
+    type IntoIter = std::iter::Map<
+        std::slice::Iter<'a, (String, String)>,
+        fn(&'a (String, String)) -> (&'a str, &'a str),
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pairs.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+}
+
+impl IntoIterator for Attributes {
+    type Item = (String, String);
+    type IntoIter = std::vec::IntoIter<(String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.pairs.into_iter()
+    }
+}
+
+/// Returns `true` if `name` can be rendered as an attribute name
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|c| {
+            c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '/' | '=' | '<' | '>')
+        })
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    let mut last = 0;
+    for (i, c) in value.char_indices() {
+        let escaped = match c {
+            '&' => "&amp;",
+            '<' => "&lt;",
+            '>' => "&gt;",
+            '"' => "&quot;",
+            '\'' => "&#39;",
+            _ => continue,
+        };
+        f.write_str(&value[last..i])?;
+        f.write_str(escaped)?;
+        last = i + 1;
+    }
+    f.write_str(&value[last..])
+}
+
+/// Values accepted on the right of `"name" => value` in `attrs!`, besides the
+/// `classnames!` grammar
+///
+/// Strings are used as is and left out when empty, like empty classes in
+/// `classnames!`. `true` adds a boolean attribute, `false` and `None` leave it out.
+pub trait AttrValue {
+    /// Returns the value, or `None` to leave the attribute out
+    fn attr_value(&self) -> Option<Cow<'_, str>>;
+}
+
+impl AttrValue for str {
+    fn attr_value(&self) -> Option<Cow<'_, str>> {
+        (!self.is_empty()).then_some(Cow::Borrowed(self))
+    }
+}
+
+impl AttrValue for String {
+    fn attr_value(&self) -> Option<Cow<'_, str>> {
+        self.as_str().attr_value()
+    }
+}
+
+impl AttrValue for Cow<'_, str> {
+    fn attr_value(&self) -> Option<Cow<'_, str>> {
+        (**self).attr_value()
+    }
+}
+
+impl AttrValue for bool {
+    fn attr_value(&self) -> Option<Cow<'_, str>> {
+        self.then_some(Cow::Borrowed(""))
+    }
+}
+
+impl<T: AttrValue + ?Sized> AttrValue for &T {
+    fn attr_value(&self) -> Option<Cow<'_, str>> {
+        (**self).attr_value()
+    }
+}
+
+impl<T: AttrValue> AttrValue for Option<T> {
+    fn attr_value(&self) -> Option<Cow<'_, str>> {
+        self.as_ref()?.attr_value()
+    }
+}
+
+macro_rules! impl_display_attr_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl AttrValue for $ty {
+                fn attr_value(&self) -> Option<Cow<'_, str>> {
+                    Some(Cow::Owned(self.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_display_attr_value!(
+    char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+);
+
+/// Builder used by the code `attrs!` and `data_attrs!` generate
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct AttrsBuilder {
+    prefix: &'static str,
+    attributes: Attributes,
+}
+
+impl AttrsBuilder {
+    pub fn new(prefix: &'static str) -> Self {
+        AttrsBuilder {
+            prefix,
+            attributes: Attributes::new(),
+        }
+    }
+
+    pub fn set<V: AttrValue + ?Sized>(&mut self, name: &str, value: &V) {
+        let name = name.trim();
+        if self.prefix.is_empty() {
+            self.attributes.set(name, value);
+        } else if !name.is_empty() {
+            self.attributes
+                .set(&format!("{}{}", self.prefix, name), value);
+        }
+    }
+
+    pub fn extend(&mut self, other: &Attributes) {
+        self.attributes.extend(other);
+    }
+
+    pub fn finish(self) -> Attributes {
+        self.attributes
+    }
+}
@@ -0,0 +1,261 @@
+//! Owned, normalized class list
+
+use std::convert::Infallible;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use classnames_core_rs::{tokens, Tokens};
+
+/// A normalized set of classes: unique classes separated by single spaces, in
+/// the order they were first added, with no leading or trailing whitespace
+///
+/// Works like the DOM's `classList`: build one from the output of
+/// `classnames!`, then [`add`](ClassList::add), [`remove`](ClassList::remove),
+/// [`toggle`](ClassList::toggle) or [`replace`](ClassList::replace) classes.
+/// Dereferences to `str`, so it can be passed to `classnames!` and anywhere a
+/// class string is expected.
+///
+/// Unlike `classnames!`, which keeps every class it is given, a `ClassList`
+/// drops repeated classes, so `ClassList::from(classnames!(...))` renders
+/// differently from the macro when a class appears twice.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{classnames, ClassList};
+///
+/// let list = ClassList::from("  btn \n btn-primary btn ");
+/// assert_eq!(list, "btn btn-primary");
+/// assert_eq!(list.iter().collect::<Vec<_>>(), ["btn", "btn-primary"]);
+/// assert_eq!(classnames!(list, (true, "active")), "btn btn-primary active");
+///
+/// let mut menu = ClassList::from(classnames!("menu", (true, "hidden")));
+/// menu.remove("hidden");
+/// menu.toggle("open", None);
+/// menu.replace("menu", "dropdown");
+/// assert_eq!(menu, "dropdown open");
+/// assert!(menu.contains("open"));
+///
+/// assert_eq!(classnames!("btn", "btn"), "btn btn");
+/// assert_eq!(ClassList::from(classnames!("btn", "btn")), "btn");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ClassList {
+    classes: String,
+    /// Number of classes in `classes`
+    len: usize,
+}
+
+impl ClassList {
+    /// Creates an empty class list
+    pub const fn new() -> Self {
+        ClassList {
+            classes: String::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the classes as a space-separated string
+    pub fn as_str(&self) -> &str {
+        &self.classes
+    }
+
+    /// Returns the underlying `String`
+    pub fn into_string(self) -> String {
+        self.classes
+    }
+
+    /// Returns `true` if the list has no classes
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// Returns the number of classes
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Iterates over the classes in order
+    pub fn iter(&self) -> Tokens<'_> {
+        tokens(&self.classes)
+    }
+
+    /// Returns `true` if the list has every class of `classes`
+    ///
+    /// Returns `false` when `classes` has no class.
+    pub fn contains(&self, classes: &str) -> bool {
+        let mut classes = tokens(classes).peekable();
+        classes.peek().is_some() && classes.all(|class| self.has(class))
+    }
+
+    /// Adds the classes of `classes` that aren't in the list yet, at the end
+    pub fn add(&mut self, classes: &str) {
+        for class in tokens(classes) {
+            if !self.has(class) {
+                if !self.classes.is_empty() {
+                    self.classes.push(' ');
+                }
+                self.classes.push_str(class);
+                self.len += 1;
+            }
+        }
+    }
+
+    /// Same as [`add`](ClassList::add)
+    pub fn push(&mut self, classes: &str) {
+        self.add(classes);
+    }
+
+    /// Removes the classes of `classes`
+    pub fn remove(&mut self, classes: &str) {
+        let removed: Vec<&str> = tokens(classes).collect();
+        if removed.iter().any(|class| self.has(class)) {
+            let kept: Vec<&str> = self
+                .iter()
+                .filter(|class| !removed.contains(class))
+                .collect();
+            let len = kept.len();
+            self.classes = kept.join(" ");
+            self.len = len;
+        }
+    }
+
+    /// Removes the classes of `classes` if they are present, adds them otherwise
+    ///
+    /// With `force`, only adds (`Some(true)`) or only removes (`Some(false)`).
+    /// Each class is toggled on its own, once even if `classes` repeats it.
+    /// Returns `true` if the list has every class of `classes` afterwards.
+    pub fn toggle(&mut self, classes: &str, force: Option<bool>) -> bool {
+        let mut toggled: Vec<&str> = Vec::new();
+        for class in tokens(classes) {
+            if !toggled.contains(&class) {
+                toggled.push(class);
+            }
+        }
+        for class in toggled {
+            if force.unwrap_or(!self.has(class)) {
+                self.add(class);
+            } else {
+                self.remove(class);
+            }
+        }
+        self.contains(classes)
+    }
+
+    /// Replaces the class `old` with the classes of `new`, in place
+    ///
+    /// Classes of `new` already in the list are not repeated. Returns `false`,
+    /// leaving the list unchanged, if `old` isn't a single class of the list.
+    pub fn replace(&mut self, old: &str, new: &str) -> bool {
+        let mut old_classes = tokens(old);
+        let (Some(old), None) = (old_classes.next(), old_classes.next()) else {
+            return false;
+        };
+        if !self.has(old) {
+            return false;
+        }
+        let mut replaced = ClassList::new();
+        for class in self.iter() {
+            replaced.add(if class == old { new } else { class });
+        }
+        *self = replaced;
+        true
+    }
+
+    /// Returns `true` if the list has the single class `class`
+    fn has(&self, class: &str) -> bool {
+        self.iter().any(|c| c == class)
+    }
+}
+
+impl Deref for ClassList {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.classes
+    }
+}
+
+impl AsRef<str> for ClassList {
+    fn as_ref(&self) -> &str {
+        &self.classes
+    }
+}
+
+impl fmt::Display for ClassList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.classes)
+    }
+}
+
+impl From<&str> for ClassList {
+    fn from(classes: &str) -> Self {
+        let mut list = ClassList::new();
+        list.add(classes);
+        list
+    }
+}
+
+impl From<String> for ClassList {
+    fn from(classes: String) -> Self {
+        ClassList::from(classes.as_str())
+    }
+}
+
+impl From<ClassList> for String {
+    fn from(list: ClassList) -> Self {
+        list.classes
+    }
+}
+
+impl PartialEq<str> for ClassList {
+    fn eq(&self, other: &str) -> bool {
+        self.classes == other
+    }
+}
+
+impl PartialEq<&str> for ClassList {
+    fn eq(&self, other: &&str) -> bool {
+        self.classes == *other
+    }
+}
+
+impl PartialEq<String> for ClassList {
+    fn eq(&self, other: &String) -> bool {
+        &self.classes == other
+    }
+}
+
+impl<'a> IntoIterator for &'a ClassList {
+    type Item = &'a str;
+    type IntoIter = Tokens<'a>;
+
+    fn into_iter(self) -> Tokens<'a> {
+        self.iter()
+    }
+}
+
+impl FromStr for ClassList {
+    type Err = Infallible;
+
+    fn from_str(classes: &str) -> Result<Self, Infallible> {
+        Ok(ClassList::from(classes))
+    }
+}
+
+impl<S: AsRef<str>> Extend<S> for ClassList {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        for classes in iter {
+            self.add(classes.as_ref());
+        }
+    }
+}
+
+impl<S: AsRef<str>> FromIterator<S> for ClassList {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut list = ClassList::new();
+        list.extend(iter);
+        list
+    }
+}
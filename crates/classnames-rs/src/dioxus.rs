@@ -0,0 +1,49 @@
+//! Dioxus integration (`dioxus` feature)
+//!
+//! `classnames!` returns a `String`, which Dioxus already accepts as an
+//! attribute value, so it can be used for `class:` in `rsx!` directly.
+//! Conditions read from signals re-run the component when they change. With
+//! this feature, `classnames!` also accepts `String` signals and memos as
+//! arguments, subscribing to them like any other read:
+//!
+//! ```rust
+//! use classnames_rs::{classnames, when};
+//! use dioxus::prelude::*;
+//!
+//! #[component]
+//! fn Tab(label: String) -> Element {
+//!     let mut active = use_signal(|| false);
+//!     let theme = use_signal(|| "tab-dark".to_string());
+//!     rsx! {
+//!         button {
+//!             class: classnames!("tab", theme, when!(active(), "tab-active")),
+//!             onclick: move |_| active.toggle(),
+//!             "{label}"
+//!         }
+//!     }
+//! }
+//! # let _ = Tab;
+//! ```
+
+use ::dioxus::prelude::{Memo, ReadOnlySignal, Readable, Signal};
+use ::dioxus::signals::{SignalData, Storage};
+
+use crate::__private::{push_normalized, ClassBuffer, PushClass};
+
+impl<S: Storage<SignalData<String>>> PushClass for Signal<String, S> {
+    fn push_class<B: ClassBuffer + ?Sized>(&self, out: &mut B) {
+        push_normalized(out, &self.read());
+    }
+}
+
+impl<S: Storage<SignalData<String>>> PushClass for ReadOnlySignal<String, S> {
+    fn push_class<B: ClassBuffer + ?Sized>(&self, out: &mut B) {
+        push_normalized(out, &self.read());
+    }
+}
+
+impl PushClass for Memo<String> {
+    fn push_class<B: ClassBuffer + ?Sized>(&self, out: &mut B) {
+        push_normalized(out, &self.read());
+    }
+}
@@ -0,0 +1,110 @@
+//! Leptos integration (`leptos` feature)
+//!
+//! `classnames!` returns a `String`, which Leptos already accepts in `class=`.
+//! For class lists that should follow signals, [`reactive_classnames!`] wraps
+//! the same grammar in a closure that Leptos re-runs whenever a signal read by
+//! the conditions changes:
+//!
+//! ```rust
+//! use classnames_rs::reactive_classnames;
+//! use leptos::prelude::*;
+//!
+//! let (active, set_active) = signal(false);
+//! let view = view! {
+//!     <button class=reactive_classnames!("btn", (active, "active"))>"Save"</button>
+//! };
+//! assert_eq!(view.to_html(), r#"<button class="btn">Save</button>"#);
+//! # let _ = set_active;
+//! ```
+//!
+//! [`reactive_classnames!`]: crate::reactive_classnames
+
+use ::leptos::prelude::{
+    ArcMemo, ArcReadSignal, ArcRwSignal, ArcSignal, Get, Memo, ReadSignal, RwSignal, Signal,
+};
+use ::leptos::tachys::reactive_graph::ReactiveFunction;
+use std::sync::{Arc, Mutex};
+
+/// Class list that is recomputed whenever the signals it reads change
+///
+/// Created by [`reactive_classnames!`](crate::reactive_classnames). It can be
+/// passed to `class=` or to any attribute, like a closure returning a `String`.
+pub struct ReactiveClasses<F>(F);
+
+impl<F> ReactiveClasses<F>
+where
+    F: FnMut() -> String + Send + 'static,
+{
+    /// Wraps a function computing the class list
+    pub fn new(f: F) -> Self {
+        ReactiveClasses(f)
+    }
+
+    /// Computes the current class list, subscribing to the signals it reads
+    pub fn get(&mut self) -> String {
+        (self.0)()
+    }
+}
+
+impl<F> ReactiveFunction for ReactiveClasses<F>
+where
+    F: FnMut() -> String + Send + 'static,
+{
+    type Output = String;
+
+    fn invoke(&mut self) -> Self::Output {
+        (self.0)()
+    }
+
+    fn into_shared(self) -> Arc<Mutex<dyn FnMut() -> Self::Output + Send>> {
+        Arc::new(Mutex::new(self.0))
+    }
+}
+
+/// A condition of [`reactive_classnames!`](crate::reactive_classnames): a plain
+/// `bool` or a `bool` signal, which is tracked
+pub trait Condition {
+    /// Returns the current value, tracking it when it is a signal
+    fn evaluate(&self) -> bool;
+}
+
+impl Condition for bool {
+    fn evaluate(&self) -> bool {
+        *self
+    }
+}
+
+impl<T: Condition + ?Sized> Condition for &T {
+    fn evaluate(&self) -> bool {
+        (**self).evaluate()
+    }
+}
+
+macro_rules! impl_signal_condition {
+    ($($signal:ident),* $(,)?) => {
+        $(
+            impl Condition for $signal<bool> {
+                fn evaluate(&self) -> bool {
+                    self.get()
+                }
+            }
+        )*
+    };
+}
+
+impl_signal_condition!(
+    ReadSignal,
+    RwSignal,
+    Memo,
+    Signal,
+    ArcReadSignal,
+    ArcRwSignal,
+    ArcMemo,
+    ArcSignal,
+);
+
+/// Evaluates a condition; used by the code `reactive_classnames!` generates
+#[doc(hidden)]
+pub fn evaluate<C: Condition + ?Sized>(condition: &C) -> bool {
+    condition.evaluate()
+}
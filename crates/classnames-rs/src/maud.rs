@@ -0,0 +1,36 @@
+//! Maud integration (`maud` feature)
+//!
+//! `classnames!` returns a `String`, which maud already renders escaped, so
+//! `class=(classnames!(...))` is safe as is. [`ClassAttr`] renders any string
+//! as a class attribute value: normalized, then escaped.
+//!
+//! ```rust
+//! use classnames_rs::{classnames, maud::ClassAttr};
+//! use maud::html;
+//!
+//! let is_active = true;
+//! let user_class = r#"  card  "><script>  "#;
+//! let markup = html! {
+//!     div class=(ClassAttr(classnames!("panel", (is_active, "active"), user_class))) {}
+//! };
+//! assert_eq!(
+//!     markup.into_string(),
+//!     r#"<div class="panel active card &quot;&gt;&lt;script&gt;"></div>"#
+//! );
+//! ```
+
+use std::fmt::Write;
+
+use ::maud::{Escaper, Render};
+
+use crate::__private::normalize;
+
+/// Class attribute value: whitespace-normalized and HTML-escaped when rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ClassAttr<T>(pub T);
+
+impl<T: AsRef<str>> Render for ClassAttr<T> {
+    fn render_to(&self, buffer: &mut String) {
+        let _ = Escaper::new(buffer).write_str(&normalize(self.0.as_ref()));
+    }
+}
@@ -0,0 +1,69 @@
+//! MiniJinja integration (`minijinja` feature)
+//!
+//! [`register`] adds a `classnames` function and filter with the semantics of
+//! `classnames!`:
+//!
+//! - Strings, numbers and booleans are added with their whitespace normalized;
+//!   like `classnames!(true)`, a boolean adds `true` or `false`
+//! - Sequences are flattened
+//! - Mappings add each key whose value is truthy, in map order
+//! - `none`, undefined values and empty strings are skipped
+//!
+//! ```rust
+//! use minijinja::{context, Environment};
+//!
+//! let mut env = Environment::new();
+//! classnames_rs::minijinja::register(&mut env);
+//!
+//! let template = r#"{{ classnames("btn", {"active": active, "disabled": disabled}, size) }}"#;
+//! let rendered = env
+//!     .render_str(template, context! { active => true, disabled => false, size => () })
+//!     .unwrap();
+//! assert_eq!(rendered, "btn active");
+//!
+//! let rendered = env.render_str(r#"{{ ["a", ["b  c"]] | classnames }}"#, ()).unwrap();
+//! assert_eq!(rendered, "a b c");
+//! ```
+
+use ::minijinja::value::{Rest, Value, ValueKind};
+use ::minijinja::{Environment, Error};
+
+use crate::__private::push_normalized;
+
+/// Registers the `classnames` function and filter
+pub fn register(env: &mut Environment<'_>) {
+    env.add_function("classnames", classnames);
+    env.add_filter("classnames", classnames);
+}
+
+/// Joins the classes of all arguments, like `classnames!`
+pub fn classnames(args: Rest<Value>) -> Result<String, Error> {
+    let mut out = String::new();
+    for arg in args.iter() {
+        push_value(&mut out, arg)?;
+    }
+    Ok(out)
+}
+
+fn push_value(out: &mut String, value: &Value) -> Result<(), Error> {
+    match value.kind() {
+        ValueKind::String => push_normalized(out, value.as_str().unwrap_or_default()),
+        ValueKind::Number => push_normalized(out, &value.to_string()),
+        // MiniJinja displays booleans as `True` and `False`
+        ValueKind::Bool => push_normalized(out, &value.is_true().to_string()),
+        ValueKind::Seq | ValueKind::Iterable => {
+            for item in value.try_iter()? {
+                push_value(out, &item)?;
+            }
+        }
+        ValueKind::Map => {
+            for key in value.try_iter()? {
+                if value.get_item(&key)?.is_true() {
+                    push_value(out, &key)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
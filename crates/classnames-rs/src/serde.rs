@@ -0,0 +1,89 @@
+//! Serde support for [`ClassList`] (`serde` feature)
+//!
+//! A class list serializes as a normalized string. Human-readable formats such
+//! as JSON and TOML deserialize it from any of:
+//!
+//! - a string: `"btn  btn-primary"`
+//! - a list of strings: `["btn", "btn-primary"]`
+//! - a map of class to `bool`: `{ "btn": true, "btn-primary": true, "hidden": false }`
+//!
+//! Compact formats such as bincode aren't self-describing, so they only read
+//! back the string a class list serializes to.
+//!
+//! Whitespace is normalized like `classnames!` does, but unlike `classnames!`
+//! the result is a [`ClassList`], so a class listed twice is only kept once.
+//! Map entries are kept in the order the format provides them.
+//!
+//! ```rust
+//! use classnames_rs::ClassList;
+//!
+//! let from_str: ClassList = serde_json::from_str(r#"" btn  btn-primary ""#).unwrap();
+//! let from_list: ClassList = serde_json::from_str(r#"["btn", "", "btn-primary"]"#).unwrap();
+//! let from_map: ClassList =
+//!     serde_json::from_str(r#"{"btn": true, "hidden": false, "btn-primary": true}"#).unwrap();
+//!
+//! assert_eq!(from_str, "btn btn-primary");
+//! assert_eq!(from_list, from_str);
+//! assert_eq!(from_map, from_str);
+//! assert_eq!(serde_json::to_string(&from_map).unwrap(), r#""btn btn-primary""#);
+//!
+//! let repeated: ClassList = serde_json::from_str(r#"["btn", "btn btn-primary"]"#).unwrap();
+//! assert_eq!(repeated, from_str);
+//! ```
+//!
+//! [`ClassList`]: crate::ClassList
+
+use std::fmt;
+
+use ::serde::de::{self, MapAccess, SeqAccess, Visitor};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ClassList;
+
+impl Serialize for ClassList {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClassList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ClassListVisitor)
+        } else {
+            deserializer.deserialize_str(ClassListVisitor)
+        }
+    }
+}
+
+struct ClassListVisitor;
+
+impl<'de> Visitor<'de> for ClassListVisitor {
+    type Value = ClassList;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a class string, a list of class strings or a map of class to bool")
+    }
+
+    fn visit_str<E: de::Error>(self, classes: &str) -> Result<ClassList, E> {
+        Ok(ClassList::from(classes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ClassList, A::Error> {
+        let mut list = ClassList::new();
+        while let Some(classes) = seq.next_element::<String>()? {
+            list.push(&classes);
+        }
+        Ok(list)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ClassList, A::Error> {
+        let mut list = ClassList::new();
+        while let Some((classes, enabled)) = map.next_entry::<String, bool>()? {
+            if enabled {
+                list.push(&classes);
+            }
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,40 @@
+//! Inline output buffer for `small_classnames!` (`smallstr` feature)
+
+use ::smallstr::SmallString;
+
+use crate::__private::ClassBuffer;
+
+/// Class string kept inline up to 64 bytes, returned by
+/// [`small_classnames!`](crate::small_classnames)
+///
+/// Dereferences to `str`; longer class lists move to the heap.
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{small_classnames, when};
+///
+/// let is_active = true;
+/// let classes = small_classnames!("btn", (is_active, "btn-active"), when!(false, "hidden"));
+/// assert_eq!(classes, "btn btn-active");
+/// assert!(!classes.spilled());
+///
+/// let long = small_classnames!(
+///     "flex items-center justify-between",
+///     "rounded-lg border border-gray-200 bg-white px-4 py-2 shadow-sm"
+/// );
+/// assert!(long.spilled());
+/// ```
+pub type SmallClasses = SmallString<[u8; 64]>;
+
+impl ClassBuffer for SmallClasses {
+    #[inline]
+    fn is_empty(&self) -> bool {
+        SmallString::is_empty(self)
+    }
+
+    #[inline]
+    fn push_str(&mut self, s: &str) {
+        SmallString::push_str(self, s);
+    }
+}
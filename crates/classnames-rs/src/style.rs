@@ -0,0 +1,156 @@
+//! Runtime support for `styles!`
+
+use std::borrow::Cow;
+
+/// Values accepted on the right of `"prop" => value` in `styles!`
+///
+/// `None` and empty values leave the property out.
+pub trait StyleValue {
+    /// Returns the value, or `None` to skip the declaration
+    fn style_value(&self) -> Option<Cow<'_, str>>;
+}
+
+impl StyleValue for str {
+    fn style_value(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl StyleValue for String {
+    fn style_value(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl StyleValue for Cow<'_, str> {
+    fn style_value(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl<T: StyleValue + ?Sized> StyleValue for &T {
+    fn style_value(&self) -> Option<Cow<'_, str>> {
+        (**self).style_value()
+    }
+}
+
+impl<T: StyleValue> StyleValue for Option<T> {
+    fn style_value(&self) -> Option<Cow<'_, str>> {
+        self.as_ref()?.style_value()
+    }
+}
+
+macro_rules! impl_display_style_value {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl StyleValue for $ty {
+                fn style_value(&self) -> Option<Cow<'_, str>> {
+                    Some(Cow::Owned(self.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+impl_display_style_value!(
+    char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64,
+);
+
+/// Declarations collected by `styles!`, in order, one per property
+#[derive(Debug, Default)]
+pub struct StyleBuilder {
+    declarations: Vec<(String, String)>,
+}
+
+impl StyleBuilder {
+    /// Creates an empty builder
+    pub fn new() -> Self {
+        StyleBuilder::default()
+    }
+
+    /// Sets `property` to `value`, replacing an earlier declaration of the same
+    /// property
+    ///
+    /// The replaced declaration is removed and the new one appended, so a later
+    /// longhand still overrides an earlier shorthand and vice versa. A value
+    /// with a `;` outside strings and parentheses would add declarations of its
+    /// own, so it is left out.
+    pub fn set<V: StyleValue + ?Sized>(&mut self, property: &str, value: &V) {
+        let Some(value) = value.style_value() else {
+            return;
+        };
+        let property = normalize_property(property);
+        let value = value.trim().trim_end_matches(';').trim_end();
+        if property.is_empty() || value.is_empty() || split_declarations(value).nth(1).is_some() {
+            return;
+        }
+        self.declarations.retain(|(p, _)| *p != property);
+        self.declarations.push((property, value.to_string()));
+    }
+
+    /// Sets every declaration of a `prop: value; ...` string
+    pub fn extend<S: AsRef<str> + ?Sized>(&mut self, styles: &S) {
+        for declaration in split_declarations(styles.as_ref()) {
+            if let Some((property, value)) = declaration.split_once(':') {
+                self.set(property, value);
+            }
+        }
+    }
+
+    /// Returns the declarations as `prop: value;`, separated by single spaces
+    pub fn finish(self) -> String {
+        let mut out = String::new();
+        for (property, value) in self.declarations {
+            if !out.is_empty() {
+                out.push(' ');
+            }
+            out.push_str(&property);
+            out.push_str(": ");
+            out.push_str(&value);
+            out.push(';');
+        }
+        out
+    }
+}
+
+/// Trims a property name and lowercases it, except for custom properties (`--x`),
+/// which are case-sensitive
+fn normalize_property(property: &str) -> String {
+    let property = property.trim();
+    if property.starts_with("--") {
+        property.to_string()
+    } else {
+        property.to_ascii_lowercase()
+    }
+}
+
+/// Splits a declaration list on `;`, ignoring semicolons in strings and
+/// parentheses (`url("a;b")`)
+fn split_declarations(styles: &str) -> impl Iterator<Item = &str> {
+    let mut declarations = Vec::new();
+    let mut start = 0;
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in styles.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (quote, c) {
+            (_, '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&styles[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&styles[start..]);
+    declarations.into_iter()
+}
@@ -0,0 +1,99 @@
+//! Tera integration (`tera` feature)
+//!
+//! [`register`] adds a `classnames` filter and function with the semantics of
+//! `classnames!`:
+//!
+//! - Strings, numbers and booleans are added with their whitespace normalized;
+//!   like `classnames!(true)`, a boolean adds `true` or `false`
+//! - Arrays are flattened
+//! - Objects add each key whose value is truthy, in map order
+//! - `null` and empty strings are skipped
+//!
+//! Tera functions only take named arguments, so the function reads its input
+//! from `classes`:
+//!
+//! ```rust
+//! use tera::{Context, Tera};
+//!
+//! let mut tera = Tera::default();
+//! classnames_rs::tera::register(&mut tera);
+//!
+//! let mut context = Context::new();
+//! context.insert("state", &serde_json::json!({"active": true, "disabled": false}));
+//! context.insert("size", &Option::<&str>::None);
+//!
+//! let rendered = tera
+//!     .render_str(r#"{{ ["btn", state, size] | classnames }}"#, &context)
+//!     .unwrap();
+//! assert_eq!(rendered, "btn active");
+//!
+//! let rendered = tera
+//!     .render_str(r#"{{ classnames(classes=["a", "b  c"]) }}"#, &context)
+//!     .unwrap();
+//! assert_eq!(rendered, "a b c");
+//! ```
+
+use std::collections::HashMap;
+
+use ::tera::{Error, Result, Tera, Value};
+
+use crate::__private::push_normalized;
+
+/// Registers the `classnames` filter and function
+pub fn register(tera: &mut Tera) {
+    tera.register_filter("classnames", filter);
+    tera.register_function("classnames", function);
+}
+
+/// `{{ value | classnames }}`
+pub fn filter(value: &Value, _: &HashMap<String, Value>) -> Result<Value> {
+    Ok(Value::String(classnames(value)))
+}
+
+/// `{{ classnames(classes=value) }}`
+pub fn function(args: &HashMap<String, Value>) -> Result<Value> {
+    let classes = args
+        .get("classes")
+        .ok_or_else(|| Error::msg("classnames() requires a `classes` argument"))?;
+    Ok(Value::String(classnames(classes)))
+}
+
+/// Joins the classes of a value, like `classnames!`
+pub fn classnames(value: &Value) -> String {
+    let mut out = String::new();
+    push_value(&mut out, value);
+    out
+}
+
+fn push_value(out: &mut String, value: &Value) {
+    match value {
+        Value::String(class) => push_normalized(out, class),
+        Value::Number(number) => push_normalized(out, &number.to_string()),
+        Value::Bool(b) => push_normalized(out, &b.to_string()),
+        Value::Array(items) => {
+            for item in items {
+                push_value(out, item);
+            }
+        }
+        Value::Object(map) => {
+            for (class, condition) in map {
+                if is_truthy(condition) {
+                    push_normalized(out, class);
+                }
+            }
+        }
+        Value::Null => {}
+    }
+}
+
+/// Tera's truthiness: `false`, `null`, zero and empty values are falsy
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
@@ -0,0 +1,171 @@
+//! Order-insensitive class assertions (`testing` feature)
+//!
+//! [`assert_classes_eq!`](crate::assert_classes_eq),
+//! [`assert_has_class!`](crate::assert_has_class) and
+//! [`assert_lacks_class!`](crate::assert_lacks_class) compare class lists as
+//! token sets, so reordering the arguments of `classnames!` doesn't break a
+//! test. Both sides, separated by `;`, take `classnames!` arguments.
+//!
+//! ```rust
+//! use classnames_rs::{assert_classes_eq, assert_has_class, assert_lacks_class, classnames};
+//!
+//! let is_active = true;
+//! let class = classnames!((is_active, "active"), "btn", "btn-primary");
+//!
+//! assert_classes_eq!(class; "btn btn-primary", (is_active, "active"));
+//! assert_has_class!(class; "active", "btn");
+//! assert_lacks_class!(class; "disabled", (!is_active, "inactive"));
+//! ```
+//!
+//! A failed assertion lists the tokens that are missing or unexpected:
+//!
+//! ```text
+//! assertion `left == right` failed: class sets differ
+//!    left: "btn active"
+//!   right: "btn disabled"
+//! missing: disabled
+//!   extra: active
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use classnames_core_rs::tokens;
+
+/// Tokens missing from and extra in a class list, compared to another one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassDiff<'a> {
+    /// Tokens of the expected list that the actual list lacks, sorted
+    pub missing: Vec<&'a str>,
+    /// Tokens of the actual list that the expected list lacks, sorted
+    pub extra: Vec<&'a str>,
+}
+
+impl<'a> ClassDiff<'a> {
+    /// Compares the token sets of `actual` and `expected`
+    pub fn new(actual: &'a str, expected: &'a str) -> Self {
+        let actual: BTreeSet<_> = tokens(actual).collect();
+        let expected: BTreeSet<_> = tokens(expected).collect();
+        ClassDiff {
+            missing: expected.difference(&actual).copied().collect(),
+            extra: actual.difference(&expected).copied().collect(),
+        }
+    }
+
+    /// Returns `true` if both lists have the same tokens
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+impl fmt::Display for ClassDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.missing.is_empty() {
+            writeln!(f, "missing: {}", self.missing.join(" "))?;
+        }
+        if !self.extra.is_empty() {
+            writeln!(f, "  extra: {}", self.extra.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_classes_eq(left: &str, right: &str) {
+    let diff = ClassDiff::new(left, right);
+    if !diff.is_empty() {
+        panic!(
+            "assertion `left == right` failed: class sets differ\n   left: {:?}\n  right: {:?}\n{}",
+            left, right, diff
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_has_class(classes: &str, expected: &str) {
+    let diff = ClassDiff::new(classes, expected);
+    if !diff.missing.is_empty() {
+        panic!(
+            "assertion failed: classes lack {:?}\nclasses: {:?}\nmissing: {}",
+            expected,
+            classes,
+            diff.missing.join(" ")
+        );
+    }
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_lacks_class(classes: &str, unexpected: &str) {
+    let classes_set: BTreeSet<_> = tokens(classes).collect();
+    let present: BTreeSet<_> = tokens(unexpected)
+        .filter(|token| classes_set.contains(token))
+        .collect();
+    if !present.is_empty() {
+        panic!(
+            "assertion failed: classes contain {:?}\n   classes: {:?}\nunexpected: {}",
+            unexpected,
+            classes,
+            present.into_iter().collect::<Vec<_>>().join(" ")
+        );
+    }
+}
+
+/// Asserts that two class lists have the same tokens, in any order
+///
+/// Both sides, separated by `;`, take `classnames!` arguments. Duplicate
+/// tokens and whitespace are ignored.
+///
+/// ```rust
+/// use classnames_rs::assert_classes_eq;
+///
+/// let is_large = true;
+/// assert_classes_eq!("btn  btn-lg", "btn"; "btn", (is_large, "btn-lg"));
+/// ```
+#[macro_export]
+macro_rules! assert_classes_eq {
+    ($($left:expr),+ $(,)? ; $($right:expr),+ $(,)?) => {
+        $crate::testing::assert_classes_eq(
+            &$crate::classnames!($($left),+),
+            &$crate::classnames!($($right),+),
+        )
+    };
+}
+
+/// Asserts that a class list contains every token on the right of `;`
+///
+/// ```rust
+/// use classnames_rs::{assert_has_class, maybe};
+///
+/// let size: Option<&str> = Some("btn-lg");
+/// assert_has_class!("btn btn-lg active"; "active", maybe!(size));
+/// ```
+#[macro_export]
+macro_rules! assert_has_class {
+    ($($classes:expr),+ $(,)? ; $($expected:expr),+ $(,)?) => {
+        $crate::testing::assert_has_class(
+            &$crate::classnames!($($classes),+),
+            &$crate::classnames!($($expected),+),
+        )
+    };
+}
+
+/// Asserts that a class list contains none of the tokens on the right of `;`
+///
+/// ```rust
+/// use classnames_rs::assert_lacks_class;
+///
+/// let is_disabled = false;
+/// assert_lacks_class!("btn active"; "hidden", (is_disabled, "active"));
+/// ```
+#[macro_export]
+macro_rules! assert_lacks_class {
+    ($($classes:expr),+ $(,)? ; $($unexpected:expr),+ $(,)?) => {
+        $crate::testing::assert_lacks_class(
+            &$crate::classnames!($($classes),+),
+            &$crate::classnames!($($unexpected),+),
+        )
+    };
+}
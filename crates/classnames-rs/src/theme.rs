@@ -0,0 +1,36 @@
+//! The theme `theme!` selects from when no enum is named
+
+/// Themes of the `theme!(value, { light: ..., dark: ..., high_contrast: ... })`
+/// form
+///
+/// # Examples
+///
+/// ```rust
+/// use classnames_rs::{classnames, theme, Theme};
+///
+/// let current = Theme::Dark;
+/// let class = classnames!(
+///     "card",
+///     theme!(current, {
+///         light: "bg-white",
+///         dark: "bg-gray-900",
+///         high_contrast: "bg-black",
+///     })
+/// );
+/// assert_eq!(class, "card bg-gray-900");
+/// assert!(current.is_dark());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+    HighContrast,
+}
+
+impl Theme {
+    /// Returns `true` for [`Theme::Dark`]
+    pub const fn is_dark(self) -> bool {
+        matches!(self, Theme::Dark)
+    }
+}
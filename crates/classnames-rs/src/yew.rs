@@ -0,0 +1,29 @@
+//! Yew integration (`yew` feature)
+//!
+//! `classnames!` returns a `String`, and Yew already converts `String` into
+//! [`Classes`] (splitting on whitespace and dropping duplicates), so the result
+//! can be passed to `class=`, `classes!` or `Classes::from` directly. In the
+//! other direction, `classnames!` accepts a [`Classes`] value as an argument:
+//!
+//! ```rust
+//! use classnames_rs::classnames;
+//! use yew::{classes, Classes};
+//!
+//! let extra: Classes = classes!("shadow", "shadow");
+//! let is_active = true;
+//! let result = classnames!("card", extra, (is_active, "active"));
+//! assert_eq!(result, "card shadow active");
+//!
+//! let classes = Classes::from(classnames!("btn btn", result));
+//! assert_eq!(classes.to_string(), "btn card shadow active");
+//! ```
+
+use ::yew::Classes;
+
+use crate::__private::{push_normalized, ClassBuffer, PushClass};
+
+impl PushClass for Classes {
+    fn push_class<B: ClassBuffer + ?Sized>(&self, out: &mut B) {
+        push_normalized(out, &self.to_string());
+    }
+}
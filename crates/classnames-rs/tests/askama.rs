@@ -0,0 +1,91 @@
+#![cfg(feature = "askama")]
+
+use std::collections::{BTreeMap, HashMap};
+
+use askama::Template;
+use classnames_rs::classnames;
+
+mod filters {
+    pub use classnames_rs::askama::classnames;
+}
+
+#[derive(Template)]
+#[template(
+    source = r#"<a class="{{ flags|classnames }}">{{ label }}</a>"#,
+    ext = "html"
+)]
+struct Link<'a, T: classnames_rs::askama::ClassConditions> {
+    label: &'a str,
+    flags: T,
+}
+
+#[derive(Template)]
+#[template(
+    source = r#"<div class="{{ class }}">{% for item in items %}<p class="{{ item|classnames }}"></p>{% endfor %}</div>"#,
+    ext = "html"
+)]
+struct List {
+    class: String,
+    items: Vec<[(&'static str, bool); 2]>,
+}
+
+#[test]
+fn test_tuple_list() {
+    let link = Link {
+        label: "Docs",
+        flags: vec![("link", true), ("  link-active ", true), ("hidden", false)],
+    };
+    assert_eq!(
+        link.render().unwrap(),
+        r#"<a class="link link-active">Docs</a>"#
+    );
+}
+
+#[test]
+fn test_maps() {
+    let btree = BTreeMap::from([("b", true), ("a", true), ("c", false)]);
+    let link = Link {
+        label: "x",
+        flags: &btree,
+    };
+    assert_eq!(link.render().unwrap(), r#"<a class="a b">x</a>"#);
+
+    // Hash maps are rendered in sorted order
+    let hash: HashMap<String, bool> = [("zeta", true), ("alpha", true), ("off", false)]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+    let link = Link {
+        label: "x",
+        flags: hash,
+    };
+    assert_eq!(link.render().unwrap(), r#"<a class="alpha zeta">x</a>"#);
+}
+
+#[test]
+fn test_output_is_escaped() {
+    let link = Link {
+        label: "x",
+        flags: [(r#"a"b"#, true), ("<c>", true)],
+    };
+    assert_eq!(
+        link.render().unwrap(),
+        r#"<a class="a&#34;b &#60;c&#62;">x</a>"#
+    );
+}
+
+#[test]
+fn test_with_classnames_output() {
+    let open = true;
+    let list = List {
+        class: classnames!("list", (open, "open")),
+        items: vec![
+            [("item", true), ("muted", false)],
+            [("item", true), ("muted", true)],
+        ],
+    };
+    assert_eq!(
+        list.render().unwrap(),
+        r#"<div class="list open"><p class="item"></p><p class="item muted"></p></div>"#
+    );
+}
@@ -0,0 +1,199 @@
+use classnames_rs::{attrs, choose, classnames, data_attrs, maybe, when, Attributes};
+
+fn pairs(attributes: &Attributes) -> Vec<(&str, &str)> {
+    attributes.iter().collect()
+}
+
+#[test]
+fn test_pairs_in_order() {
+    assert!(attrs!().is_empty());
+    let attributes = attrs!("role" => "tab", "aria-selected" => "true", "tabindex" => -1,);
+    assert_eq!(
+        pairs(&attributes),
+        [
+            ("role", "tab"),
+            ("aria-selected", "true"),
+            ("tabindex", "-1")
+        ]
+    );
+    assert_eq!(attributes.len(), 3);
+    assert_eq!(attributes.get("ROLE"), Some("tab"));
+    assert_eq!(attributes.get("id"), None);
+}
+
+#[test]
+fn test_value_types() {
+    let label: Option<&str> = None;
+    let owned = String::from("menu");
+    assert_eq!(
+        attrs!(
+            "data-active" => true,
+            "data-hidden" => false,
+            "aria-label" => label,
+            "aria-controls" => &owned,
+            "title" => "",
+            "data-count" => 3u8
+        )
+        .to_string(),
+        r#"data-active aria-controls="menu" data-count="3""#
+    );
+}
+
+#[test]
+fn test_conditions() {
+    let open = true;
+    let disabled = false;
+    let orientation: Option<&str> = Some(" vertical ");
+    let attributes = attrs!(
+        "data-state" => choose!(open, "open", "closed"),
+        "data-highlighted" => when!(disabled, "yes"),
+        "data-orientation" => maybe!(orientation),
+        (open, "aria-expanded" => "true", "data-open" => true),
+        (disabled, "data-disabled" => true),
+        when!(!disabled, "aria-disabled" => "false"),
+        "data-size" => if open { "lg" } else { "sm" }
+    );
+    assert_eq!(
+        pairs(&attributes),
+        [
+            ("data-state", "open"),
+            ("data-orientation", "vertical"),
+            ("aria-expanded", "true"),
+            ("data-open", ""),
+            ("aria-disabled", "false"),
+            ("data-size", "lg"),
+        ]
+    );
+}
+
+#[test]
+fn test_names_and_overrides() {
+    let name = String::from(" Data-Id ");
+    let attributes = attrs!(
+        "data-state" => "closed",
+        name => 7,
+        ("DATA-STATE") => "open",
+        ("bad name") => "x",
+        ("") => "x"
+    );
+    assert_eq!(
+        pairs(&attributes),
+        [("data-state", "open"), ("data-id", "7")]
+    );
+}
+
+#[test]
+fn test_later_absent_values_remove() {
+    let label: Option<&str> = None;
+    let attributes = attrs!(
+        "data-open" => true,
+        "aria-label" => "Menu",
+        "title" => "Close",
+        "id" => "menu",
+        "data-open" => false,
+        "aria-label" => label,
+        "title" => ""
+    );
+    assert_eq!(pairs(&attributes), [("id", "menu")]);
+
+    let mut attributes = Attributes::new();
+    attributes.set("hidden", &true);
+    attributes.set("HIDDEN", &false);
+    assert!(attributes.is_empty());
+}
+
+#[test]
+fn test_escaping() {
+    let attributes = attrs!("title" => r#"<a href="x">Tom & 'Jerry'</a>"#);
+    assert_eq!(
+        attributes.render(),
+        r#"title="&lt;a href=&quot;x&quot;&gt;Tom &amp; &#39;Jerry&#39;&lt;/a&gt;""#
+    );
+}
+
+#[test]
+fn test_data_attrs() {
+    let disabled = true;
+    let base = attrs!("role" => "switch");
+    let attributes = data_attrs!(
+        ..base,
+        "state" => "on",
+        (disabled, "disabled" => true),
+        ("") => "ignored"
+    );
+    assert_eq!(
+        attributes.to_string(),
+        r#"role="switch" data-state="on" data-disabled"#
+    );
+}
+
+#[test]
+fn test_spread() {
+    let base = data_attrs!("state" => "closed", "side" => "top");
+    let attributes = attrs!(..base, "data-state" => "open", "id" => "popover");
+    assert_eq!(
+        pairs(&attributes),
+        [
+            ("data-state", "open"),
+            ("data-side", "top"),
+            ("id", "popover")
+        ]
+    );
+    assert_eq!(
+        attributes.into_vec(),
+        [
+            ("data-state".to_string(), "open".to_string()),
+            ("data-side".to_string(), "top".to_string()),
+            ("id".to_string(), "popover".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_spread_keeps_boolean_attributes() {
+    let base = attrs!("hidden" => true, "data-disabled" => true);
+    let attributes = attrs!(..base, "id" => "popover");
+    assert_eq!(
+        attributes.to_string(),
+        r#"hidden data-disabled id="popover""#
+    );
+
+    let mut extended = Attributes::new();
+    extended.extend(&base);
+    assert_eq!(extended, base);
+}
+
+#[test]
+fn test_values_use_the_classnames_grammar() {
+    let open = true;
+    let disabled = false;
+    let size = 2;
+    let attributes = data_attrs!(
+        "state" => (open, "open", "closed"),
+        "disabled" => (disabled, "yes"),
+        "size" => match size {
+            1 => "sm",
+            2 => "md",
+            _ => None,
+        },
+        "side" => if open { "top" } else { "bottom" },
+        "label" => "  main   menu ",
+        "empty" => {
+            let label: Option<&str> = None;
+            label
+        }
+    );
+    assert_eq!(
+        pairs(&attributes),
+        [
+            ("data-state", "open"),
+            ("data-size", "md"),
+            ("data-side", "top"),
+            ("data-label", "main menu"),
+        ]
+    );
+    assert_eq!(
+        attributes.get("data-state"),
+        Some(classnames!((open, "open", "closed")).as_str())
+    );
+}
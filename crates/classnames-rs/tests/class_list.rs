@@ -0,0 +1,134 @@
+use classnames_rs::{classnames, ClassList};
+
+#[test]
+fn test_normalization_and_uniqueness() {
+    let list = ClassList::from(" btn\tactive  btn \u{A0}active lg ");
+    assert_eq!(list, "btn active lg");
+    assert_eq!(list.len(), 3);
+    assert_eq!(ClassList::from(String::from("  ")), "");
+    assert!(ClassList::new().is_empty());
+
+    let mut list = ClassList::new();
+    list.push("a b");
+    list.push(" b  c ");
+    assert_eq!(list, "a b c");
+}
+
+#[test]
+fn test_duplicates_differ_from_macro() {
+    let classes = classnames!("btn", (true, "active"), "btn lg");
+    assert_eq!(classes, "btn active btn lg");
+    let list = ClassList::from(classes.as_str());
+    assert_eq!(list, "btn active lg");
+    assert_ne!(list.to_string(), classes);
+    assert_eq!(list.len(), 3);
+}
+
+#[test]
+fn test_add_and_remove() {
+    let mut list = ClassList::from(classnames!("menu", (true, "hidden"), "shadow"));
+    list.add("open  menu");
+    assert_eq!(list, "menu hidden shadow open");
+
+    list.remove("hidden");
+    assert_eq!(list, "menu shadow open");
+    list.remove("shadow missing open");
+    assert_eq!(list, "menu");
+    list.remove("menu");
+    assert_eq!(list, "");
+    list.add("");
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_contains() {
+    let list = ClassList::from("btn btn-primary active");
+    assert!(list.contains("btn"));
+    assert!(list.contains(" active  btn "));
+    assert!(!list.contains("btn-"));
+    assert!(!list.contains("btn disabled"));
+    assert!(!list.contains(""));
+    assert!(!list.contains("   "));
+}
+
+#[test]
+fn test_toggle() {
+    let mut list = ClassList::from("menu");
+    assert!(list.toggle("open", None));
+    assert_eq!(list, "menu open");
+    assert!(!list.toggle("open", None));
+    assert_eq!(list, "menu");
+
+    assert!(list.toggle("open", Some(true)));
+    assert!(list.toggle("open", Some(true)));
+    assert_eq!(list, "menu open");
+    assert!(!list.toggle("closed", Some(false)));
+    assert!(!list.toggle("open", Some(false)));
+    assert_eq!(list, "menu");
+
+    // Each class is toggled on its own
+    assert!(!list.toggle("menu wide", None));
+    assert_eq!(list, "wide");
+    assert!(!list.toggle("", None));
+
+    // A repeated class is toggled once
+    assert!(list.toggle("open open", None));
+    assert_eq!(list, "wide open");
+    assert!(!list.toggle(" open  wide open ", None));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn test_len_follows_changes() {
+    let mut list = ClassList::from("a b c");
+    assert_eq!(list.len(), 3);
+    list.add("c d");
+    assert_eq!(list.len(), 4);
+    list.remove("a missing");
+    assert_eq!(list.len(), 3);
+    list.toggle("b e", None);
+    assert_eq!((list.as_str(), list.len()), ("c d e", 3));
+    list.replace("d", "f g c");
+    assert_eq!((list.as_str(), list.len()), ("c f g e", 4));
+    list.remove("c f g e");
+    assert_eq!(list.len(), 0);
+    assert_eq!(list, ClassList::new());
+}
+
+#[test]
+fn test_replace() {
+    let mut list = ClassList::from("btn btn-primary lg");
+    assert!(list.replace("btn-primary", "btn-danger"));
+    assert_eq!(list, "btn btn-danger lg");
+
+    assert!(list.replace(" btn-danger ", "outline  lg"));
+    assert_eq!(list, "btn outline lg");
+    assert!(list.replace("lg", "btn"));
+    assert_eq!(list, "btn outline");
+    assert!(list.replace("btn", ""));
+    assert_eq!(list, "outline");
+
+    assert!(!list.replace("missing", "x"));
+    assert!(!list.replace("outline btn", "x"));
+    assert!(!list.replace("", "x"));
+    assert_eq!(list, "outline");
+}
+
+#[test]
+fn test_conversions() {
+    let list: ClassList = " b a b ".parse().unwrap();
+    assert_eq!(list.to_string(), "b a");
+    assert_eq!(String::from(list.clone()), "b a");
+
+    let collected: ClassList = ["btn", " btn-lg btn", ""].into_iter().collect();
+    assert_eq!(collected, "btn btn-lg");
+
+    let mut extended = collected.clone();
+    extended.extend(vec![String::from("active"), String::from("btn-lg")]);
+    assert_eq!(extended, "btn btn-lg active");
+    assert_eq!(
+        (&extended).into_iter().collect::<Vec<_>>(),
+        ["btn", "btn-lg", "active"]
+    );
+    assert_eq!(classnames!(extended, "focus"), "btn btn-lg active focus");
+}
@@ -0,0 +1,120 @@
+use std::borrow::Cow;
+
+use classnames_rs::{choose, cow_classnames, maybe, theme, when};
+
+#[test]
+fn test_borrows_single_literal() {
+    let is_active = true;
+    let is_hidden = false;
+
+    let class = cow_classnames!("  btn ", " btn-lg");
+    assert_eq!(class, "btn btn-lg");
+    assert!(matches!(class, Cow::Borrowed(_)));
+
+    let class = cow_classnames!((is_active, "active"), (is_hidden, "hidden"), "");
+    assert_eq!(class, "active");
+    assert!(matches!(class, Cow::Borrowed(_)));
+
+    let class = cow_classnames!((is_hidden, "a", "b"), (is_hidden, ["c"]));
+    assert_eq!(class, "b");
+    assert!(matches!(class, Cow::Borrowed(_)));
+
+    let class = cow_classnames!("btn", (is_active, "active"), (is_hidden, "a", "b"));
+    assert_eq!(class, "btn active b");
+    assert!(matches!(class, Cow::Borrowed(_)));
+
+    let class = cow_classnames!((is_hidden, "hidden"));
+    assert_eq!(class, "");
+    assert!(matches!(class, Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_borrows_literal_if_chains() {
+    for (size, expected) in [(0, "sm"), (1, "md"), (2, "lg")] {
+        let class = cow_classnames!(if size == 0 {
+            "sm"
+        } else if size == 1 {
+            " md "
+        } else {
+            "lg"
+        });
+        assert_eq!(class, expected);
+        assert!(matches!(class, Cow::Borrowed(_)));
+    }
+
+    let is_open = false;
+    let class = cow_classnames!(if is_open {
+        "open"
+    });
+    assert_eq!(class, "");
+    assert!(matches!(class, Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_allocates_when_joining() {
+    let is_active = true;
+    let size: Option<&str> = Some("lg");
+    let name = String::from("card");
+
+    let class = cow_classnames!("btn", &name);
+    assert_eq!(class, "btn card");
+    assert!(matches!(class, Cow::Owned(_)));
+
+    let class = cow_classnames!(
+        maybe!(size),
+        choose!(is_active, "on", "off"),
+        &name,
+        "shadow"
+    );
+    assert_eq!(class, "lg on card shadow");
+    assert!(matches!(class, Cow::Owned(_)));
+
+    // Helper macros with a dynamic value build a `String`
+    let class = cow_classnames!(choose!(is_active, [&name], "off"));
+    assert_eq!(class, "card");
+    assert!(matches!(class, Cow::Owned(_)));
+}
+
+#[test]
+fn test_borrows_literal_helpers() {
+    let is_active = true;
+    let is_hidden = false;
+
+    let class = cow_classnames!(choose!(is_active, "on", "off"));
+    assert_eq!(class, "on");
+    assert!(matches!(class, Cow::Borrowed(_)));
+
+    let class = cow_classnames!(
+        "btn",
+        when!(is_hidden, "hidden"),
+        choose!(is_hidden, "a", " b ")
+    );
+    assert_eq!(class, "btn b");
+    assert!(matches!(class, Cow::Borrowed(_)));
+
+    let class: Cow<'static, str> = choose!(is_active, " on ", "off");
+    assert!(matches!(class, Cow::Borrowed("on")));
+    let class: Cow<'static, str> = when!(is_hidden, "hidden");
+    assert!(matches!(class, Cow::Borrowed("")));
+
+    #[allow(dead_code)]
+    enum Theme {
+        Light,
+        Dark,
+    }
+    let current = Theme::Dark;
+    let class: Cow<'static, str> = theme!(
+        current,
+        Theme {
+            light: "bg-white",
+            dark: "bg-black"
+        }
+    );
+    assert!(matches!(class, Cow::Borrowed("bg-black")));
+
+    // A list or a dynamic value still builds a `String`
+    let class: String = choose!(is_active, ["on", "lg"], "off");
+    assert_eq!(class, "on lg");
+    let class: String = when!(is_active, class);
+    assert_eq!(class, "on lg");
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "dioxus")]
+
+use classnames_rs::{choose, classnames, when};
+use dioxus::dioxus_core::{AttributeValue, Mutation, Mutations};
+use dioxus::prelude::*;
+
+/// Values of every `class` attribute set by the mutations, in order
+fn class_values(mutations: &Mutations) -> Vec<String> {
+    mutations
+        .edits
+        .iter()
+        .filter_map(|edit| match edit {
+            Mutation::SetAttribute {
+                name: "class",
+                value: AttributeValue::Text(value),
+                ..
+            } => Some(value.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Signals of the component under test, shared with the test through context
+#[derive(Clone, Copy)]
+struct Controls {
+    active: Signal<bool>,
+    disabled: Signal<bool>,
+    variant: Signal<String>,
+}
+
+fn app() -> Element {
+    let active = use_signal(|| false);
+    let disabled = use_signal(|| false);
+    let variant = use_signal(|| "btn-primary".to_string());
+    let shadow = use_memo(move || choose!(disabled(), "", "shadow").to_string());
+    use_context_provider(|| Controls {
+        active,
+        disabled,
+        variant,
+    });
+
+    rsx! {
+        button {
+            class: classnames!(
+                "btn",
+                variant,
+                (active(), "active"),
+                when!(disabled(), "disabled"),
+                shadow
+            ),
+            "Save"
+        }
+    }
+}
+
+fn controls(dom: &VirtualDom) -> Controls {
+    dom.in_runtime(|| ScopeId::APP.in_runtime(consume_context::<Controls>))
+}
+
+#[test]
+fn test_initial_render() {
+    let mut dom = VirtualDom::new(app);
+    let mutations = dom.rebuild_to_vec();
+    assert_eq!(class_values(&mutations), ["btn btn-primary shadow"]);
+}
+
+#[test]
+fn test_signal_updates() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut controls = controls(&dom);
+
+    dom.in_runtime(|| controls.active.set(true));
+    let mutations = dom.render_immediate_to_vec();
+    assert_eq!(class_values(&mutations), ["btn btn-primary active shadow"]);
+
+    dom.in_runtime(|| {
+        controls.active.set(false);
+        controls.disabled.set(true);
+        controls.variant.set("  btn-danger  ".to_string());
+    });
+    let mutations = dom.render_immediate_to_vec();
+    assert_eq!(class_values(&mutations), ["btn btn-danger disabled"]);
+}
+
+#[test]
+fn test_signal_arguments() {
+    let mut dom = VirtualDom::new(|| {
+        let size = use_signal(|| "lg".to_string());
+        let label: ReadOnlySignal<String> = ReadOnlySignal::new(Signal::new("label".to_string()));
+        rsx! { span { class: classnames!(size, label, (false, "hidden")) } }
+    });
+    let mutations = dom.rebuild_to_vec();
+    assert_eq!(class_values(&mutations), ["lg label"]);
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "leptos")]
+
+use classnames_rs::{choose, classnames, reactive_classnames, when};
+use leptos::prelude::*;
+
+#[test]
+fn test_plain_string_class() {
+    let active = true;
+    let view = view! { <div class=classnames!("card", (active, "active"))></div> };
+    assert_eq!(view.to_html(), r#"<div class="card active"></div>"#);
+}
+
+#[test]
+fn test_signal_conditions() {
+    let (active, set_active) = signal(true);
+    let disabled = RwSignal::new(false);
+    let mut classes = reactive_classnames!(
+        "btn",
+        (active, "active"),
+        when!(disabled, "disabled"),
+        if active.get() { "on" } else { "off" }
+    );
+    assert_eq!(classes.get(), "btn active on");
+
+    set_active.set(false);
+    disabled.set(true);
+    assert_eq!(classes.get(), "btn disabled off");
+}
+
+#[test]
+fn test_memo_and_choose() {
+    let count = RwSignal::new(1);
+    let is_even = Memo::new(move |_| count.get() % 2 == 0);
+    let mut classes = reactive_classnames!(
+        "counter",
+        choose!(is_even, "even", "odd"),
+        (&true, "tracked", "untracked")
+    );
+    assert_eq!(classes.get(), "counter odd tracked");
+
+    count.set(2);
+    assert_eq!(classes.get(), "counter even tracked");
+}
+
+#[test]
+fn test_arc_signals_and_plain_bools() {
+    let open = ArcRwSignal::new(false);
+    let tracked = open.clone();
+    let large = true;
+    let mut classes = reactive_classnames!("modal", (tracked, "open"), (large, "lg"));
+    assert_eq!(classes.get(), "modal lg");
+
+    open.set(true);
+    assert_eq!(classes.get(), "modal open lg");
+}
+
+#[test]
+fn test_view_ssr() {
+    let (active, set_active) = signal(false);
+    let render = move || {
+        view! {
+            <button class=reactive_classnames!("btn", (active, "active"))>"Save"</button>
+        }
+        .to_html()
+    };
+    assert_eq!(render(), r#"<button class="btn">Save</button>"#);
+
+    set_active.set(true);
+    assert_eq!(render(), r#"<button class="btn active">Save</button>"#);
+}
+
+#[test]
+fn test_view_attribute() {
+    let selected = RwSignal::new(true);
+    let view = view! {
+        <li data-state=reactive_classnames!("item", (selected, "selected"))></li>
+    };
+    assert_eq!(view.to_html(), r#"<li data-state="item selected"></li>"#);
+}
+
+#[test]
+fn test_expression_conditions() {
+    let count = RwSignal::new(1);
+    let limit = 2;
+    let mut classes = reactive_classnames!(
+        "badge",
+        (count.get() > 2, "many"),
+        (limit > 1, "limited"),
+        when!(count.get() == 1, "single"),
+        choose!(count.get() > limit, "over", "under"),
+        if count.get() > 2 { "wide" } else { "narrow" }
+    );
+    assert_eq!(classes.get(), "badge limited single under narrow");
+
+    count.set(3);
+    assert_eq!(classes.get(), "badge many limited over wide");
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "maud")]
+
+use classnames_rs::{choose, classnames, maud::ClassAttr, when};
+use maud::{html, Render};
+
+#[test]
+fn test_class_attr_escapes() {
+    let markup = html! {
+        span class=(ClassAttr(r#"a&b "quoted" <tag>"#)) {}
+    };
+    assert_eq!(
+        markup.into_string(),
+        r#"<span class="a&amp;b &quot;quoted&quot; &lt;tag&gt;"></span>"#
+    );
+}
+
+#[test]
+fn test_class_attr_normalizes() {
+    assert_eq!(
+        ClassAttr("  btn \n  btn-primary\t").render().into_string(),
+        "btn btn-primary"
+    );
+    assert_eq!(ClassAttr(String::new()).render().into_string(), "");
+}
+
+#[test]
+fn test_classnames_in_template() {
+    let items = [("Home", true), ("About", false)];
+    let dense = true;
+    let markup = html! {
+        ul class=(ClassAttr(classnames!("nav", when!(dense, "nav-dense")))) {
+            @for (label, current) in items {
+                li class=(ClassAttr(classnames!("nav-item", choose!(current, "current", "")))) {
+                    (label)
+                }
+            }
+        }
+    };
+    assert_eq!(
+        markup.into_string(),
+        concat!(
+            r#"<ul class="nav nav-dense">"#,
+            r#"<li class="nav-item current">Home</li>"#,
+            r#"<li class="nav-item">About</li>"#,
+            "</ul>"
+        )
+    );
+}
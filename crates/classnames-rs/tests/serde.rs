@@ -0,0 +1,121 @@
+#![cfg(feature = "serde")]
+
+use classnames_rs::{classnames, ClassList};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ButtonTheme {
+    base: ClassList,
+    primary: ClassList,
+    #[serde(default)]
+    disabled: Option<ClassList>,
+}
+
+#[test]
+fn test_json_shapes() {
+    let cases = [
+        r#""  btn   btn-lg ""#,
+        r#"["btn", " btn-lg ", ""]"#,
+        r#"["btn btn-lg"]"#,
+        r#"{"btn": true, "hidden": false, "btn-lg": true}"#,
+    ];
+    for json in cases {
+        let list: ClassList = serde_json::from_str(json).unwrap();
+        assert_eq!(list, "btn btn-lg", "input: {}", json);
+    }
+
+    let empty: ClassList = serde_json::from_str("[]").unwrap();
+    assert!(empty.is_empty());
+    let empty: ClassList = serde_json::from_str(r#"{"a": false}"#).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_json_errors() {
+    assert!(serde_json::from_str::<ClassList>("42").is_err());
+    assert!(serde_json::from_str::<ClassList>("[1]").is_err());
+    assert!(serde_json::from_str::<ClassList>(r#"{"a": "yes"}"#).is_err());
+}
+
+#[test]
+fn test_json_round_trip() {
+    let list = ClassList::from(classnames!("card", (true, "card-active"), " shadow "));
+    let json = serde_json::to_string(&list).unwrap();
+    assert_eq!(json, r#""card card-active shadow""#);
+    assert_eq!(serde_json::from_str::<ClassList>(&json).unwrap(), list);
+}
+
+#[test]
+fn test_duplicates_are_dropped() {
+    let cases = [
+        r#""btn btn-lg  btn""#,
+        r#"["btn", "btn-lg btn", "btn-lg"]"#,
+        r#"{"btn": true, "btn-lg": true, "btn btn-lg": true}"#,
+    ];
+    for json in cases {
+        let list: ClassList = serde_json::from_str(json).unwrap();
+        assert_eq!(list, "btn btn-lg", "input: {}", json);
+    }
+    assert_eq!(classnames!("btn btn-lg  btn"), "btn btn-lg btn");
+}
+
+#[test]
+fn test_bincode_round_trip() {
+    let theme = ButtonTheme {
+        base: ClassList::from("btn  rounded"),
+        primary: ClassList::from("bg-blue-500 text-white"),
+        disabled: Some(ClassList::from("opacity-50")),
+    };
+    let bytes = bincode::serialize(&theme).unwrap();
+    assert_eq!(bincode::deserialize::<ButtonTheme>(&bytes).unwrap(), theme);
+
+    let list: ClassList = bincode::deserialize(&bincode::serialize(" a  b ").unwrap()).unwrap();
+    assert_eq!(list, "a b");
+
+    let list: ClassList = bincode::deserialize(&bincode::serialize(" a  b a ").unwrap()).unwrap();
+    assert_eq!(list, "a b");
+}
+
+#[test]
+fn test_toml_config() {
+    let config = r#"
+        base = "btn  rounded"
+        primary = ["bg-blue-500", "text-white"]
+
+        [disabled]
+        opacity-50 = true
+        cursor-pointer = false
+        cursor-not-allowed = true
+    "#;
+    let theme: ButtonTheme = toml::from_str(config).unwrap();
+    assert_eq!(theme.base, "btn rounded");
+    assert_eq!(theme.primary, "bg-blue-500 text-white");
+    assert_eq!(
+        theme.disabled.as_deref(),
+        Some("opacity-50 cursor-not-allowed")
+    );
+
+    let serialized = toml::to_string(&theme).unwrap();
+    let round_trip: ButtonTheme = toml::from_str(&serialized).unwrap();
+    assert_eq!(round_trip, theme);
+
+    let minimal: ButtonTheme = toml::from_str("base = \"\"\nprimary = []").unwrap();
+    assert!(minimal.base.is_empty() && minimal.primary.is_empty());
+    assert_eq!(minimal.disabled, None);
+}
+
+#[test]
+fn test_used_with_classnames() {
+    let theme: ButtonTheme = serde_json::from_str(
+        r#"{"base": "btn", "primary": {"btn-primary": true}, "disabled": "btn-disabled"}"#,
+    )
+    .unwrap();
+    let is_primary = true;
+    let is_disabled = false;
+    let classes = classnames!(
+        &theme.base,
+        (is_primary, &theme.primary),
+        (is_disabled, theme.disabled.clone().unwrap_or_default())
+    );
+    assert_eq!(classes, "btn btn-primary");
+}
@@ -0,0 +1,48 @@
+//! Run with `cargo test -p classnames-rs --features smallstr --test smallstr`
+#![cfg(feature = "smallstr")]
+
+use classnames_rs::{choose, classnames, maybe, small_classnames, when, SmallClasses};
+
+#[test]
+#[allow(unused_braces)]
+fn test_same_output_as_classnames() {
+    let is_active = true;
+    let is_disabled = false;
+    let size: Option<&str> = Some(" lg ");
+    let variant = String::from("primary");
+
+    let small: SmallClasses = small_classnames!(
+        "  btn ",
+        (is_active, "active"),
+        (is_disabled, "disabled", ["enabled", maybe!(size)]),
+        when!(is_active, "focus"),
+        choose!(is_disabled, "muted", "bright"),
+        variant,
+        if is_active { "on" } else { "off" }
+    );
+    let string = classnames!(
+        "  btn ",
+        (is_active, "active"),
+        (is_disabled, "disabled", ["enabled", maybe!(size)]),
+        when!(is_active, "focus"),
+        choose!(is_disabled, "muted", "bright"),
+        variant,
+        if is_active { "on" } else { "off" }
+    );
+    assert_eq!(small, string.as_str());
+    assert_eq!(&*small, "btn active enabled lg focus bright primary on");
+    assert!(!small.spilled());
+}
+
+#[test]
+fn test_spills_past_inline_capacity() {
+    let class = "x".repeat(70);
+    let small = small_classnames!("btn", class, "y");
+    assert!(small.spilled());
+    assert_eq!(small.len(), 4 + 70 + 2);
+    assert!(small.ends_with(" y"));
+
+    let empty = small_classnames!("", (false, "hidden"));
+    assert!(empty.is_empty());
+    assert!(!empty.spilled());
+}
@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use classnames_rs::styles;
+
+#[test]
+fn test_declarations() {
+    assert_eq!(styles!(), "");
+    assert_eq!(styles!("color" => "red"), "color: red;");
+    assert_eq!(
+        styles!("color" => "red", "display" => "none",),
+        "color: red; display: none;"
+    );
+}
+
+#[test]
+fn test_normalization() {
+    assert_eq!(
+        styles!(" Color " => "  red ; ", "MARGIN" => "0 auto", "--Brand-Color" => "#FFF"),
+        "color: red; margin: 0 auto; --Brand-Color: #FFF;"
+    );
+    assert_eq!(styles!("color" => "", "width" => "   "), "");
+    // Literal names are checked at compile time, runtime ones are skipped
+    let empty = "";
+    assert_eq!(styles!(empty => "red"), "");
+}
+
+#[test]
+fn test_value_types() {
+    let owned = String::from("block");
+    let cow: Cow<str> = Cow::Borrowed("1px solid");
+    let z_index = 10;
+    let opacity = 0.5;
+    assert_eq!(
+        styles!(
+            "display" => owned,
+            "border" => cow,
+            "z-index" => z_index,
+            "opacity" => opacity,
+            "width" => format!("{}%", 50)
+        ),
+        "display: block; border: 1px solid; z-index: 10; opacity: 0.5; width: 50%;"
+    );
+}
+
+#[test]
+fn test_option_values() {
+    let color: Option<&str> = Some("red");
+    let width: Option<u32> = None;
+    let height: Option<String> = Some("2rem".into());
+    assert_eq!(
+        styles!("color" => color, "width" => width, "height" => &height),
+        "color: red; height: 2rem;"
+    );
+}
+
+#[test]
+fn test_conditional_declarations() {
+    let hidden = false;
+    let active = true;
+    let count = 3;
+    assert_eq!(
+        styles!(
+            "color" => "black",
+            (hidden, "display" => "none"),
+            (active, "color" => "blue", "font-weight" => 700),
+            (count > 2, (active, "outline" => "none"))
+        ),
+        "color: blue; font-weight: 700; outline: none;"
+    );
+}
+
+#[test]
+fn test_overrides() {
+    // The replaced declaration moves to the end, so shorthands keep their order
+    assert_eq!(
+        styles!("margin-top" => "1px", "margin" => 0, "margin-top" => "2px"),
+        "margin: 0; margin-top: 2px;"
+    );
+    assert_eq!(styles!("color" => "red", "COLOR" => "blue"), "color: blue;");
+    // An empty override does not remove the earlier value
+    let none: Option<&str> = None;
+    assert_eq!(styles!("color" => "red", "color" => none), "color: red;");
+}
+
+#[test]
+fn test_values_cannot_add_declarations() {
+    let color = String::from("red; position: fixed");
+    assert_eq!(
+        styles!("display" => "block", "color" => color.as_str()),
+        "display: block;"
+    );
+    assert_eq!(
+        styles!("content" => r#""a;b""#, "background" => "url(a;b.png);"),
+        r#"content: "a;b"; background: url(a;b.png);"#
+    );
+}
+
+#[test]
+fn test_spreads() {
+    let base = styles!("color" => "red", "padding" => "4px");
+    let user = String::from(r#"  background: url("a;b.png") ; PADDING:8px;;invalid; color : ; "#);
+    assert_eq!(
+        styles!(..base, "color" => "green", ..user),
+        r#"color: green; background: url("a;b.png"); padding: 8px;"#
+    );
+
+    let compact = true;
+    assert_eq!(
+        styles!("gap" => "1rem", (compact, .."gap: 0; padding: 0")),
+        "gap: 0; padding: 0;"
+    );
+}
+
+#[test]
+fn test_parenthesized_property() {
+    let prefix = "--size";
+    assert_eq!(
+        styles!((prefix) => "1px", ("border-".to_string() + "width") => 2),
+        "--size: 1px; border-width: 2;"
+    );
+}
+
+#[test]
+fn test_builder_name_is_hygienic() {
+    let styles = "color: red";
+    assert_eq!(styles!(..styles, "top" => 0), "color: red; top: 0;");
+}
+
+#[test]
+fn test_property_validation_escapes() {
+    // Vendor-prefixed, custom and parenthesized property names are not checked
+    assert_eq!(
+        styles!(
+            "-webkit-tap-highlight-color" => "transparent",
+            "--any-Name" => 1,
+            ("future-property") => "on",
+            "Z-Index" => 2
+        ),
+        "-webkit-tap-highlight-color: transparent; --any-Name: 1; future-property: on; z-index: 2;"
+    );
+}
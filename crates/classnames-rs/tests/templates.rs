@@ -0,0 +1,159 @@
+//! Conformance of the template engine integrations with `classnames!`
+//!
+//! Run with `cargo test -p classnames-rs --features minijinja,tera --test templates`
+#![cfg(any(feature = "minijinja", feature = "tera"))]
+
+use classnames_rs::{classnames, maybe};
+use serde_json::{json, Value};
+
+/// `classes` values and the class string every engine must render
+fn cases() -> Vec<(Value, &'static str)> {
+    vec![
+        (json!("btn"), "btn"),
+        (json!("  btn \t btn-primary\n"), "btn btn-primary"),
+        (json!(""), ""),
+        (json!("   "), ""),
+        (json!(null), ""),
+        (json!(true), "true"),
+        (json!(false), "false"),
+        (json!(42), "42"),
+        (json!(["a", "", null, "b  c", false]), "a b c false"),
+        (json!(["a", ["b", ["c", null]], []]), "a b c"),
+        (
+            json!({"active": true, "disabled": false, "hidden": null}),
+            "active",
+        ),
+        (
+            json!({"a": 1, "b": 0, "c": "yes", "d": "", "e": [1], "f": []}),
+            "a c e",
+        ),
+        (json!({" spaced  out ": true}), "spaced out"),
+        (
+            json!(["btn", {"btn-active": true, "btn-off": false}, null, "  lg "]),
+            "btn btn-active lg",
+        ),
+        (json!([]), ""),
+        (json!({}), ""),
+        (json!("Åsa\u{3000}ünï"), "Åsa ünï"),
+    ]
+}
+
+#[test]
+fn test_cases_match_macro() {
+    // The string forms of the cases, through the Rust macro
+    let size: Option<&str> = None;
+    assert_eq!(classnames!("  btn \t btn-primary\n"), cases()[1].1);
+    assert_eq!(classnames!("", "   ", maybe!(size)), "");
+    assert_eq!(classnames!(true), cases()[5].1);
+    assert_eq!(classnames!("a", "", "b  c", false), cases()[8].1);
+    assert_eq!(
+        classnames!("btn", (true, "btn-active"), (false, "btn-off"), "  lg "),
+        cases()[13].1
+    );
+    assert_eq!(classnames!("Åsa\u{3000}ünï"), cases()[16].1);
+}
+
+/// Renders `classes` with the filter and the function of every enabled engine
+fn render(classes: &Value) -> Vec<String> {
+    let mut rendered = Vec::new();
+    #[cfg(feature = "minijinja")]
+    {
+        let mut env = minijinja::Environment::empty();
+        classnames_rs::minijinja::register(&mut env);
+        let context = minijinja::Value::from_serialize(json!({ "classes": classes }));
+        for template in ["{{ classes | classnames }}", "{{ classnames(classes) }}"] {
+            rendered.push(env.render_str(template, &context).unwrap());
+        }
+    }
+    #[cfg(feature = "tera")]
+    {
+        let mut tera = tera::Tera::default();
+        classnames_rs::tera::register(&mut tera);
+        let context = tera::Context::from_value(json!({ "classes": classes })).unwrap();
+        for template in [
+            "{{ classes | classnames }}",
+            "{{ classnames(classes=classes) }}",
+        ] {
+            rendered.push(tera.render_str(template, &context).unwrap());
+        }
+    }
+    rendered
+}
+
+/// Booleans add their string form, as with `classnames!`
+#[test]
+fn test_booleans_match_macro() {
+    let active = true;
+    let disabled = false;
+    let is_open = || true;
+    let cases = [
+        (classnames!("a", true), json!(["a", true])),
+        (classnames!(false, "a", "b"), json!([false, "a", "b"])),
+        (
+            classnames!("a", !active, !disabled, "b"),
+            json!(["a", !active, !disabled, "b"]),
+        ),
+        (classnames!(is_open(), "a"), json!([is_open(), "a"])),
+        (
+            classnames!("a", (active, [true, "b"])),
+            json!(["a", [true, "b"]]),
+        ),
+        (classnames!("a", 42), json!(["a", 42])),
+    ];
+    for (expected, classes) in cases {
+        for output in render(&classes) {
+            assert_eq!(output, expected, "classes = {}", classes);
+        }
+    }
+}
+
+#[cfg(feature = "minijinja")]
+#[test]
+fn test_minijinja_conformance() {
+    use minijinja::{Environment, Value as JinjaValue};
+
+    let mut env = Environment::empty();
+    classnames_rs::minijinja::register(&mut env);
+    for (value, expected) in cases() {
+        let context = JinjaValue::from_serialize(json!({ "classes": value }));
+        let filter = env
+            .render_str("{{ classes | classnames }}", &context)
+            .unwrap();
+        let function = env
+            .render_str("{{ classnames(classes) }}", &context)
+            .unwrap();
+        assert_eq!(filter, expected, "filter, classes = {}", value);
+        assert_eq!(function, expected, "function, classes = {}", value);
+    }
+
+    let rendered = env
+        .render_str(
+            r#"{{ classnames("btn", undefined_value, none, {"on": 1}, ["x"]) }}"#,
+            (),
+        )
+        .unwrap();
+    assert_eq!(rendered, "btn on x");
+}
+
+#[cfg(feature = "tera")]
+#[test]
+fn test_tera_conformance() {
+    use tera::{Context, Tera};
+
+    let mut tera = Tera::default();
+    classnames_rs::tera::register(&mut tera);
+    for (value, expected) in cases() {
+        let context = Context::from_value(json!({ "classes": value })).unwrap();
+        let filter = tera
+            .render_str("{{ classes | classnames }}", &context)
+            .unwrap();
+        let function = tera
+            .render_str("{{ classnames(classes=classes) }}", &context)
+            .unwrap();
+        assert_eq!(filter, expected, "filter, classes = {}", value);
+        assert_eq!(function, expected, "function, classes = {}", value);
+    }
+
+    let error = tera.render_str("{{ classnames() }}", &Context::new());
+    assert!(error.is_err());
+}
@@ -1,4 +1,4 @@
-use classnames_rs::{choose, classnames, maybe, pretty_classname, when};
+use classnames_rs::{choose, classnames, maybe, pretty_classname, theme, when};
 
 #[test]
 fn test_basic_strings() {
@@ -168,6 +168,25 @@ fn test_whitespace_normalization() {
     );
 }
 
+#[test]
+fn test_every_value_is_normalized() {
+    let open = true;
+    let spaced = String::from(" menu\t open ");
+    let icon: Option<&str> = Some("  icon  ");
+    assert_eq!(
+        classnames!(
+            (open, "a   b"),
+            (!open, "c", "d \n e"),
+            (open, spaced.clone(), "closed"),
+            &spaced,
+            if open { " f  g " } else { "" },
+            { icon },
+            spaced.trim_start()
+        ),
+        "a b d e menu open menu open f g icon menu open"
+    );
+}
+
 #[test]
 fn test_pretty_classname() {
     let messy_class = "btn   primary\n  large";
@@ -407,3 +426,242 @@ fn test_edge_cases() {
         "theme-dark size-lg"
     );
 }
+
+#[derive(Clone, Copy)]
+enum Variant {
+    Primary,
+    Secondary,
+    Ghost,
+    Link,
+    Plain,
+}
+
+fn button(variant: Variant, size: Option<&str>, loading: bool) -> String {
+    classnames!(
+        "btn",
+        match variant {
+            Variant::Primary => "btn-primary",
+            Variant::Secondary => Some("btn-secondary"),
+            Variant::Ghost => ["btn-ghost", (loading, "btn-ghost-loading"), maybe!(size)],
+            Variant::Link if loading => None,
+            Variant::Link => size.map(|size| format!("link-{}", size)),
+            Variant::Plain => (),
+        }
+    )
+}
+
+#[test]
+fn test_match_arms() {
+    assert_eq!(button(Variant::Primary, None, false), "btn btn-primary");
+    assert_eq!(button(Variant::Secondary, None, false), "btn btn-secondary");
+    assert_eq!(
+        button(Variant::Ghost, Some(" lg "), true),
+        "btn btn-ghost btn-ghost-loading lg"
+    );
+    assert_eq!(button(Variant::Ghost, None, false), "btn btn-ghost");
+    assert_eq!(button(Variant::Link, Some("sm"), true), "btn");
+    assert_eq!(button(Variant::Link, Some("sm"), false), "btn link-sm");
+    assert_eq!(button(Variant::Link, None, false), "btn");
+    assert_eq!(button(Variant::Plain, Some("sm"), true), "btn");
+}
+
+#[test]
+fn test_match_arm_value_types() {
+    let owned = String::from("  owned   class ");
+    let cow: std::borrow::Cow<str> = "cow".into();
+    let optional: Option<String> = Some("optional".into());
+    for (n, expected) in [
+        (0, "owned class"),
+        (1, "cow"),
+        (2, "optional"),
+        (3, "nested"),
+        (4, ""),
+        (5, "fallback"),
+    ] {
+        let result = classnames!(match n {
+            0 => &owned,
+            1 => &*cow,
+            2 => optional.as_deref(),
+            3 => {
+                let parts = ["nest", "ed"];
+                parts.concat()
+            }
+            4 => {}
+            _ => "  fallback  ",
+        });
+        assert_eq!(result, expected);
+    }
+}
+
+#[test]
+#[allow(unused_braces)]
+fn test_class_lists() {
+    let open = true;
+    let closed = !open;
+    let accent: Option<&str> = Some(" accent ");
+    let size = "lg";
+
+    assert_eq!(
+        classnames!(
+            "menu",
+            (open, ["menu-open", "  shadow  ", maybe!(accent)]),
+            (closed, ["never", "shown"]),
+            (open, [size, (closed, "nested-off"), (open, ["nested-on"])])
+        ),
+        "menu menu-open shadow accent lg nested-on"
+    );
+    assert_eq!(classnames!((open, [])), "");
+    assert_eq!(
+        classnames!((closed, ["a", "b"], ["c", when!(open, "d")])),
+        "c d"
+    );
+    assert_eq!(classnames!((open, ["a", "b"], "c")), "a b");
+    assert_eq!(
+        classnames!((closed, "a", ["b", if open { "c" } else { "" }])),
+        "b c"
+    );
+}
+
+#[test]
+fn test_choose_lists() {
+    let primary = true;
+    let icon: Option<&str> = None;
+    assert_eq!(
+        choose!(primary, ["btn-primary", maybe!(icon)], ["btn-secondary"]),
+        "btn-primary"
+    );
+    assert_eq!(choose!(!primary, ["a  b"], "  c  "), "c");
+    assert_eq!(
+        classnames!(
+            "btn",
+            choose!(!primary, "solid", ["outline", (primary, "ring")])
+        ),
+        "btn outline ring"
+    );
+}
+
+#[test]
+fn test_static_lookup_table() {
+    for mask in 0..8u8 {
+        let [a, b, c] = [mask & 1 != 0, mask & 2 != 0, mask & 4 != 0];
+        let table = classnames!(" btn ", (a, "active  focus"), "", (b, "x", "y "), (c, " "));
+        let mut expected = vec!["btn"];
+        if a {
+            expected.extend(["active", "focus"]);
+        }
+        expected.push(if b { "x" } else { "y" });
+        assert_eq!(table, expected.join(" "));
+    }
+
+    // Conditions are evaluated once each, in order
+    let mut calls = Vec::new();
+    let mut check = |i: u8, value: bool| {
+        calls.push(i);
+        value
+    };
+    let result = classnames!((check(1, true), "a"), "b", (check(2, false), "c", "d"));
+    assert_eq!(result, "a b d");
+    assert_eq!(calls, [1, 2]);
+
+    // Seven tuples fall back to building the string piece by piece
+    let flags = [true, false, true, false, true, false, true];
+    let result = classnames!(
+        (flags[0], "a"),
+        (flags[1], "b"),
+        (flags[2], "c"),
+        (flags[3], "d"),
+        (flags[4], "e"),
+        (flags[5], "f"),
+        (flags[6], "g")
+    );
+    assert_eq!(result, "a c e g");
+
+    macro_rules! forward {
+        ($($arg:expr),*) => {
+            classnames!($($arg),*)
+        };
+    }
+    assert_eq!(forward!("btn", (true, "on", "off")), "btn on");
+}
+
+mod themes {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Theme {
+        Light,
+        Dark,
+        HighContrast,
+    }
+}
+
+#[test]
+fn test_theme_macro() {
+    use themes::Theme;
+
+    let expected = [
+        (Theme::Light, "card bg-white text-gray-900"),
+        (Theme::Dark, "card bg-gray-900 text-white shadow-lg"),
+        (Theme::HighContrast, "card bg-black text-yellow-300"),
+    ];
+    for (current, expected) in expected {
+        let is_raised = true;
+        let result = classnames!(
+            "card",
+            theme!(
+                current,
+                themes::Theme {
+                    light: "  bg-white   text-gray-900 ",
+                    dark: ["bg-gray-900 text-white", (is_raised, "shadow-lg")],
+                    high_contrast: String::from("bg-black text-yellow-300"),
+                }
+            )
+        );
+        assert_eq!(result, expected);
+    }
+
+    // References and entries in any order
+    let current = &Theme::Dark;
+    let class = theme!(
+        current,
+        Theme {
+            high_contrast: "hc",
+            dark: "dark",
+            light: "light",
+        }
+    );
+    assert_eq!(class, "dark");
+}
+
+#[test]
+fn test_theme_macro_with_crate_theme() {
+    use classnames_rs::Theme;
+
+    let expected = [
+        (Theme::Light, "card bg-white"),
+        (Theme::Dark, "card bg-gray-900 shadow-lg"),
+        (Theme::HighContrast, "card bg-black"),
+    ];
+    for (current, expected) in expected {
+        let is_raised = true;
+        let result = classnames!(
+            "card",
+            theme!(current, {
+                light: "bg-white",
+                dark: ["bg-gray-900", (is_raised, "shadow-lg")],
+                high_contrast: "bg-black",
+            })
+        );
+        assert_eq!(result, expected);
+    }
+
+    let current = Theme::default();
+    assert_eq!(current, Theme::Light);
+    assert!(!current.is_dark());
+    assert_eq!(
+        theme!(current, { dark: "dark", high_contrast: "hc", light: "light" }),
+        "light"
+    );
+    assert_eq!(
+        classnames!(choose!(Theme::Dark.is_dark(), "bg-gray-900", "bg-white")),
+        "bg-gray-900"
+    );
+}
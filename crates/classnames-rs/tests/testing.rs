@@ -0,0 +1,95 @@
+//! Run with `cargo test -p classnames-rs --features testing --test testing`
+#![cfg(feature = "testing")]
+
+use std::panic::catch_unwind;
+
+use classnames_rs::testing::ClassDiff;
+use classnames_rs::{
+    assert_classes_eq, assert_has_class, assert_lacks_class, choose, classnames, maybe, when,
+};
+
+/// Message of the panic raised by `f`
+fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = catch_unwind(f).expect_err("assertion should fail");
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[test]
+#[allow(unused_braces)]
+fn test_assert_classes_eq() {
+    let is_active = true;
+    let size: Option<&str> = Some("lg");
+    let class = classnames!((is_active, "active"), "btn", maybe!(size));
+
+    assert_classes_eq!(class; "btn", "active lg");
+    assert_classes_eq!(&class; maybe!(size), when!(is_active, "active"), "btn",);
+    assert_classes_eq!("  b a  a "; "a b");
+    assert_classes_eq!(
+        "btn", if is_active { "active" } else { "" };
+        choose!(is_active, ["active", "btn"], ["btn"])
+    );
+    assert_classes_eq!(""; "", (false, "hidden"));
+}
+
+#[test]
+fn test_assert_classes_eq_message() {
+    let message = panic_message(|| assert_classes_eq!("btn active"; "btn", "disabled"));
+    assert_eq!(
+        message,
+        "assertion `left == right` failed: class sets differ\n   \
+         left: \"btn active\"\n  \
+         right: \"btn disabled\"\n\
+         missing: disabled\n  \
+         extra: active\n"
+    );
+
+    let message = panic_message(|| assert_classes_eq!("a b"; "a"));
+    assert!(message.ends_with("  extra: b\n"), "{}", message);
+    assert!(!message.contains("missing:"), "{}", message);
+}
+
+#[test]
+fn test_assert_has_class() {
+    let is_open = true;
+    let class = classnames!("menu", (is_open, ["menu-open", "shadow"]));
+    assert_has_class!(class; "shadow");
+    assert_has_class!(class; "menu-open menu", (!is_open, "closed"));
+
+    let message = panic_message(|| assert_has_class!("menu shadow"; "menu", "open", "wide"));
+    assert_eq!(
+        message,
+        "assertion failed: classes lack \"menu open wide\"\n\
+         classes: \"menu shadow\"\n\
+         missing: open wide"
+    );
+}
+
+#[test]
+fn test_assert_lacks_class() {
+    let is_disabled = false;
+    let class = classnames!("btn", (is_disabled, "disabled"));
+    assert_lacks_class!(class; "disabled", "hidden");
+    assert_lacks_class!(class; (!is_disabled, "btn-disabled"));
+
+    let message =
+        panic_message(|| assert_lacks_class!("btn hidden active"; "active hidden sr-only"));
+    assert_eq!(
+        message,
+        "assertion failed: classes contain \"active hidden sr-only\"\n   \
+         classes: \"btn hidden active\"\n\
+         unexpected: active hidden"
+    );
+}
+
+#[test]
+fn test_class_diff() {
+    let diff = ClassDiff::new("c a b", "d b a");
+    assert_eq!(diff.missing, ["d"]);
+    assert_eq!(diff.extra, ["c"]);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.to_string(), "missing: d\n  extra: c\n");
+    assert!(ClassDiff::new("a  b", "b a a").is_empty());
+}
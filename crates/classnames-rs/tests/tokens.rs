@@ -0,0 +1,77 @@
+use classnames_rs::{choose, classnames, maybe, tokens, when};
+
+#[test]
+fn test_same_grammar_as_classnames() {
+    let open = true;
+    let hint: Option<&str> = Some("hint");
+    assert_eq!(
+        tokens!(
+            " label ",
+            (open, "panel"),
+            maybe!(hint),
+            choose!(open, "a", "b")
+        ),
+        classnames!(
+            " label ",
+            (open, "panel"),
+            maybe!(hint),
+            choose!(open, "a", "b")
+        )
+    );
+    assert_eq!(tokens!(), "");
+    assert_eq!(tokens!("", "  "), "");
+}
+
+#[test]
+#[allow(unused_braces)]
+fn test_aria_and_part() {
+    let invalid = true;
+    let described_by = tokens!("email-label", (invalid, "email-error"), "email-hint");
+    assert_eq!(described_by, "email-label email-error email-hint");
+
+    let selected = false;
+    let part = tokens!("tab", if selected { "tab-selected" } else { "" });
+    assert_eq!(part, "tab");
+}
+
+#[test]
+#[allow(unused_braces)]
+fn test_rel() {
+    let external = true;
+    let sponsored = false;
+    let rel = tokens!(
+        rel: "noopener",
+        (external, "noreferrer External"),
+        when!(sponsored, "sponsored"),
+        choose!(external, "nofollow", "bookmark"),
+        if external { "ugc" } else { "help" }
+    );
+    assert_eq!(rel, "noopener noreferrer External nofollow ugc");
+
+    // Runtime values are not checked
+    let custom = "x-custom";
+    assert_eq!(tokens!(rel: "preload", custom), "preload x-custom");
+}
+
+#[test]
+fn test_sandbox() {
+    let trusted = true;
+    let sandbox = tokens!(
+        sandbox: "allow-scripts allow-forms",
+        (trusted, "allow-same-origin allow-popups", "")
+    );
+    assert_eq!(
+        sandbox,
+        "allow-scripts allow-forms allow-same-origin allow-popups"
+    );
+    assert_eq!(tokens!(sandbox:), "");
+}
+
+#[test]
+fn test_attribute_name_is_not_a_path() {
+    mod rel {
+        pub const DEFAULT: &str = "noopener";
+    }
+    let rel = "local";
+    assert_eq!(tokens!(rel::DEFAULT, rel), "noopener local");
+}
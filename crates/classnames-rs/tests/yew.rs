@@ -0,0 +1,112 @@
+//! Server rendering needs `yew/ssr`: `cargo test -p classnames-rs --features yew,yew/ssr --test yew`
+#![cfg(feature = "yew")]
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use classnames_rs::{choose, classnames, maybe, when};
+use yew::prelude::*;
+use yew::LocalServerRenderer;
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Minimal executor so the test needs no async runtime
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct ButtonProps {
+    #[prop_or_default]
+    class: Classes,
+    #[prop_or_default]
+    primary: bool,
+    #[prop_or_default]
+    size: Option<&'static str>,
+}
+
+#[function_component]
+fn Button(props: &ButtonProps) -> Html {
+    let class = classnames!(
+        "btn",
+        props.class.clone(),
+        choose!(props.primary, "btn-primary", "btn-default"),
+        maybe!(props.size)
+    );
+    html! { <button class={class}>{"Save"}</button> }
+}
+
+#[test]
+fn test_classes_argument() {
+    let extra = classes!("shadow", "rounded");
+    assert_eq!(classnames!("card", extra), "card shadow rounded");
+
+    let empty = Classes::new();
+    assert_eq!(classnames!("card", empty, when!(true, "open")), "card open");
+
+    let reference = &classes!("a", "b");
+    assert_eq!(classnames!(reference, (true, "c")), "a b c");
+}
+
+#[test]
+fn test_conversions() {
+    let classes = Classes::from(classnames!("btn  btn-primary", "btn", (true, "active")));
+    assert!(classes.contains("btn-primary"));
+    assert_eq!(classes.to_string(), "btn btn-primary active");
+
+    let mut merged = classes!("card");
+    merged.push(classnames!("card", (false, "hidden"), "elevated"));
+    assert_eq!(classnames!(merged), "card elevated");
+}
+
+#[test]
+fn test_ssr_render() {
+    let html = block_on(
+        LocalServerRenderer::<Button>::with_props(ButtonProps {
+            class: classes!("wide", "btn"),
+            primary: true,
+            size: Some("lg"),
+        })
+        .hydratable(false)
+        .render(),
+    );
+    // `class=` goes through `Classes`, which drops the repeated `btn`
+    assert_eq!(
+        html,
+        r#"<button class="btn wide btn-primary lg">Save</button>"#
+    );
+}
+
+#[test]
+fn test_ssr_render_into_classes() {
+    #[function_component]
+    fn Card() -> Html {
+        let elevated = true;
+        let class: Classes = classnames!("card", "card", (elevated, "elevated")).into();
+        html! { <div class={classes!(class, "mt-2")}></div> }
+    }
+
+    let html = block_on(
+        LocalServerRenderer::<Card>::new()
+            .hydratable(false)
+            .render(),
+    );
+    assert_eq!(html, r#"<div class="card elevated mt-2"></div>"#);
+}
@@ -0,0 +1,11 @@
+// Opts the `minify` test in to class name minification and writes its class
+// name manifest.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=tests/minify.rs");
+    println!("cargo:rustc-env=CLASSNAMES_MINIFY=minify");
+
+    let out_dir = std::env::var_os("OUT_DIR").expect("cargo sets OUT_DIR for build scripts");
+    classnames_extract_rs::minify::write_manifest(&["tests/minify.rs"], out_dir.as_ref())
+        .expect("tests/minify.rs can be scanned");
+}
@@ -0,0 +1,9 @@
+//! Integration tests for `classnames-rs` features that are configured from a
+//! build script or a file next to `Cargo.toml`, kept out of `classnames-rs` so
+//! its own build and the builds of crates depending on it don't need either
+//!
+//! - `tests/minify.rs`: `build.rs` opts it in to `minify` and writes its manifest
+//! - `tests/design_tokens.rs`: `design_tokens!` and `@` references read
+//!   `tokens.toml`
+//!
+//! The tests live in `tests/`; this library is empty.
@@ -0,0 +1,69 @@
+//! Run with `cargo test -p classnames-tests-rs --test design_tokens`
+//!
+//! `@` references and `design_tokens!()` read `tokens.toml` at the root of this
+//! crate.
+
+use std::borrow::Cow;
+
+use classnames_rs::{choose, classnames, cow_classnames, design_tokens};
+
+mod tokens {
+    classnames_rs::design_tokens!();
+}
+
+mod fixture {
+    super::design_tokens!("tokens.toml");
+}
+
+#[test]
+fn test_accessors() {
+    assert_eq!(tokens::SURFACE, "bg-white dark:bg-gray-900");
+    assert_eq!(tokens::button::PRIMARY, "bg-blue-600 text-white");
+    assert_eq!(tokens::button::GHOST, "bg-transparent text-blue-600");
+    assert_eq!(tokens::button::size::LG, "px-4 py-2 text-lg");
+    assert_eq!(fixture::button::BASE, tokens::button::BASE);
+    assert_eq!(
+        classnames!(tokens::button::BASE, tokens::button::size::SM),
+        "inline-flex items-center rounded px-2 py-1 text-sm"
+    );
+}
+
+#[test]
+#[allow(unused_braces)]
+fn test_references() {
+    let is_primary = true;
+    let is_large = false;
+    assert_eq!(
+        classnames!(
+            @button.base,
+            (is_primary, @button.primary, @button.ghost),
+            (is_large, @button.size.lg),
+            "shadow"
+        ),
+        "inline-flex items-center rounded bg-blue-600 text-white shadow"
+    );
+    assert_eq!(
+        classnames!(choose!(is_large, @button.size.lg, @button.size.sm)),
+        "px-2 py-1 text-sm"
+    );
+    assert_eq!(
+        classnames!(if is_primary { @surface } else { "" }),
+        tokens::SURFACE
+    );
+
+    let class = cow_classnames!((is_primary, @button.primary, @button.ghost));
+    assert!(matches!(class, Cow::Borrowed("bg-blue-600 text-white")));
+}
+
+#[test]
+fn test_pattern_bindings_are_kept() {
+    let size = 3;
+    let class = classnames!(
+        @button.base,
+        match size {
+            n @ 0..=2 if n > 0 => @button.size.sm,
+            _ => @button.size.lg,
+        }
+    );
+    assert_eq!(class, "inline-flex items-center rounded px-4 py-2 text-lg");
+}
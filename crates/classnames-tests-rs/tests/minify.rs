@@ -0,0 +1,165 @@
+//! Run with `cargo test -p classnames-tests-rs --test minify`
+//!
+//! `build.rs` lists this test crate in `CLASSNAMES_MINIFY` and writes the
+//! manifest of this file.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use classnames_const_rs::classnames_concat;
+use classnames_rs::{attrs, choose, classnames, maybe, theme, tokens, when};
+
+fn manifest() -> BTreeMap<String, String> {
+    let content = std::fs::read_to_string(concat!(env!("OUT_DIR"), "/classnames-manifest.json"))
+        .expect("the build script writes the manifest");
+    serde_json::from_str(&content).expect("the manifest is a JSON object")
+}
+
+#[test]
+fn test_literals_are_minified() {
+    let result = classnames!("btn", "btn-primary");
+    assert_ne!(result, "btn btn-primary");
+    let names: Vec<_> = result.split(' ').collect();
+    assert_eq!(names.len(), 2);
+    assert!(names[0].len() < "btn".len());
+    assert!(names[1].len() < "btn-primary".len());
+}
+
+#[test]
+fn test_names_are_short_and_unique() {
+    assert_eq!(classnames!("btn").len(), 1);
+    assert_eq!(
+        classnames!("card", "card-body", "card-title"),
+        classnames!("card card-body  card-title")
+    );
+
+    let manifest = manifest();
+    assert!(manifest.contains_key("btn"));
+    for (class, short) in &manifest {
+        assert!(short.len() <= class.len(), "{} -> {}", class, short);
+        let owners = manifest.values().filter(|s| *s == short).count();
+        assert_eq!(owners, 1, "{} is used for several classes", short);
+    }
+}
+
+#[test]
+fn test_manifest_depends_only_on_the_sources() {
+    let file = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/minify.rs");
+    let mut extractor = classnames_extract_rs::Extractor::new();
+    extractor.add_file(&file).unwrap();
+    assert_eq!(
+        classnames_extract_rs::minify::short_names(extractor),
+        manifest()
+    );
+}
+
+#[allow(unused_braces)]
+#[test]
+fn test_mapping_is_consistent() {
+    let is_active = true;
+    let a = classnames!("btn  btn-primary", (is_active, "active"));
+    let b = classnames!("btn", "btn-primary", when!(is_active, "active"));
+    assert_eq!(a, b);
+
+    assert_eq!(
+        classnames!(choose!(is_active, "btn", "other")),
+        classnames!("btn")
+    );
+    assert_eq!(classnames!(maybe!(Some("btn"))), classnames!("btn"));
+    assert_eq!(
+        classnames!(if is_active { "active" } else { "inactive" }),
+        classnames!("active")
+    );
+
+    const CLASSES: &str = classnames_concat!("btn", "btn-primary");
+    assert_eq!(CLASSES, classnames!("btn", "btn-primary"));
+}
+
+#[test]
+fn test_nested_theme_is_minified() {
+    enum Theme {
+        Light,
+        Dark,
+    }
+    let current = Theme::Dark;
+    let classes = classnames!(theme!(
+        current,
+        Theme {
+            light: "light-card",
+            dark: "active"
+        }
+    ));
+    assert_eq!(classes, classnames!("active"));
+    assert_eq!(
+        classnames!(theme!(
+            Theme::Light,
+            Theme {
+                light: "btn",
+                dark: "other"
+            }
+        )),
+        classnames!("btn")
+    );
+
+    // The crate's own `Theme`
+    assert_eq!(
+        classnames!(theme!(classnames_rs::Theme::Dark, {
+            light: "other",
+            dark: "active",
+            high_contrast: "inactive",
+        })),
+        classnames!("active")
+    );
+}
+
+#[test]
+fn test_helpers_outside_class_lists_are_not_minified() {
+    let is_active = true;
+    assert_eq!(choose!(is_active, "label", "placeholder"), "label");
+    assert_eq!(when!(is_active, "shown"), "shown");
+    assert_eq!(
+        tokens!("noopener", when!(is_active, "noreferrer")),
+        "noopener noreferrer"
+    );
+
+    let attrs = attrs!("title" => choose!(is_active, "Close", "Open"));
+    assert_eq!(attrs.to_string(), r#"title="Close""#);
+
+    // Their classes keep their names in class lists too
+    assert_eq!(classnames!("label", (is_active, "shown")), "label shown");
+}
+
+#[test]
+fn test_constants_keep_their_names() {
+    const SHORT: &str = "a";
+    const TITLE: &str = "card-title";
+
+    let classes = classnames!(SHORT, "btn", "btn-primary");
+    let names: Vec<_> = classes.split(' ').collect();
+    assert_eq!(names[0], "a");
+    assert!(!names[1..].contains(&"a"), "{}", classes);
+    assert_eq!(classnames!(TITLE, "card-title"), "card-title card-title");
+}
+
+#[test]
+fn test_runtime_values_pass_through() {
+    let dynamic = "dynamic-class";
+    assert_eq!(classnames!(dynamic), "dynamic-class");
+
+    let owned = String::from("owned-class");
+    assert_eq!(classnames!((true, owned.as_str())), "owned-class");
+}
+
+#[test]
+fn test_manifest_lists_literals() {
+    let manifest = manifest();
+    for class in ["btn", "btn-primary", "active", "inactive", "other", "a"] {
+        assert!(
+            manifest.contains_key(class),
+            "{} missing from {:?}",
+            class,
+            manifest
+        );
+    }
+    assert!(!manifest.contains_key("dynamic-class"));
+}
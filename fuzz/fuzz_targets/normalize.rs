@@ -0,0 +1,54 @@
+//! Checks every normalization path of `classnames-core-rs` against
+//! `str::split_whitespace` on arbitrary UTF-8 input.
+//!
+//! Run with `cargo +nightly fuzz run normalize` from the repository root.
+#![no_main]
+
+use classnames_core_rs::{
+    affix_into, affixed_len, as_str, next_token, normalize, normalize_into, normalized_len,
+    push_normalized, token_count, tokens,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let expected: Vec<&str> = input.split_whitespace().collect();
+    let joined = expected.join(" ");
+
+    assert_eq!(normalize(input), joined);
+    assert_eq!(tokens(input).collect::<Vec<_>>(), expected);
+    assert_eq!(token_count(input), expected.len());
+    assert_eq!(normalized_len(input), joined.len());
+    assert_eq!(
+        affixed_len(input, "p-", "-s"),
+        joined.len() + expected.len() * 4
+    );
+
+    // What `normalize_to` and `affix_to` run, with a runtime-sized buffer
+    let mut bytes = vec![0; joined.len()];
+    assert_eq!(normalize_into(input, &mut bytes), joined.len());
+    assert_eq!(as_str(&bytes), joined);
+
+    let affixed: Vec<String> = expected
+        .iter()
+        .map(|token| format!("p-{}-s", token))
+        .collect();
+    let affixed = affixed.join(" ");
+    let mut bytes = vec![0; affixed.len()];
+    assert_eq!(affix_into(input, "p-", "-s", &mut bytes), affixed.len());
+    assert_eq!(as_str(&bytes), affixed);
+
+    let mut pos = 0;
+    while let Some((start, end)) = next_token(input, pos) {
+        assert!(input.is_char_boundary(start) && input.is_char_boundary(end));
+        pos = end;
+    }
+
+    let mut out = String::from("base");
+    push_normalized(&mut out, input);
+    let mut expected_out = String::from("base");
+    if !joined.is_empty() {
+        expected_out.push(' ');
+        expected_out.push_str(&joined);
+    }
+    assert_eq!(out, expected_out);
+});